@@ -0,0 +1,114 @@
+// a data structure microbenchmark of the spatial index on synthetic entries,
+// it doesn't run the game systems, so it says nothing about frame times
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use bevy::prelude::*;
+use kingdoms_arena::{Kingdom, Lane, SpatialEntry, SpatialIndex};
+
+const SUBJECT_COUNTS: [usize; 4] = [100, 1_000, 5_000, 10_000];
+const BATTLEFIELD_EXTENSION: f32 = 20.0;
+const QUERY_RADIUS: f32 = 5.0;
+const ITERATIONS: u32 = 10;
+
+fn main() {
+    println!(
+        "{:>8} {:>12} {:>12} {:>12} {:>12} {:>12}",
+        "subjects", "rebuild", "frontlines", "within", "nearest", "naive within"
+    );
+    for count in SUBJECT_COUNTS {
+        benchmark(count);
+    }
+}
+
+fn benchmark(count: usize) {
+    let entries: Vec<_> = (0..count).map(random_entry).collect();
+    let mut index = SpatialIndex::default();
+
+    let rebuild = time(|| index.rebuild(entries.iter().copied()));
+
+    // the same single pass per kingdom that the frontlines system does
+    let frontlines = time(|| {
+        for kingdom in [Kingdom::Elven, Kingdom::Monster] {
+            let mut frontlines = [[None; 2]; Lane::ALL.len()];
+            let mut unset = 2 * Lane::ALL.len();
+            for entry in index.advancing(kingdom) {
+                let Some(lane) = entry.lane else {
+                    continue;
+                };
+                let frontline = &mut frontlines[lane.index()][entry.flying as usize];
+                if frontline.is_none() {
+                    *frontline = Some(entry.entity);
+                    unset -= 1;
+                    if unset == 0 {
+                        break;
+                    }
+                }
+            }
+            black_box(frontlines);
+        }
+    });
+
+    let within = time(|| {
+        for (kingdom, entry) in &entries {
+            black_box(
+                index
+                    .within(kingdom.rival(), entry.position, QUERY_RADIUS)
+                    .count(),
+            );
+        }
+    });
+
+    let nearest = time(|| {
+        for (kingdom, entry) in &entries {
+            black_box(index.nearest(kingdom.rival(), entry.position, |other| {
+                other.lane == entry.lane
+            }));
+        }
+    });
+
+    // what every system did before the index: scan all units for each unit
+    let naive_within = time(|| {
+        for (kingdom, entry) in &entries {
+            black_box(
+                entries
+                    .iter()
+                    .filter(|(other_kingdom, other)| {
+                        other_kingdom != kingdom
+                            && (other.position - entry.position).abs() <= QUERY_RADIUS
+                    })
+                    .count(),
+            );
+        }
+    });
+
+    println!(
+        "{:>8} {:>12?} {:>12?} {:>12?} {:>12?} {:>12?}",
+        count, rebuild, frontlines, within, nearest, naive_within
+    );
+}
+
+fn random_entry(id: usize) -> (Kingdom, SpatialEntry) {
+    let kingdom = if fastrand::bool() {
+        Kingdom::Elven
+    } else {
+        Kingdom::Monster
+    };
+    let entry = SpatialEntry {
+        entity: Entity::from_raw(id as u32),
+        position: (fastrand::f32() - 0.5) * 2.0 * BATTLEFIELD_EXTENSION,
+        lane: Some(Lane::random()),
+        flying: fastrand::u8(..10) == 0,
+    };
+    (kingdom, entry)
+}
+
+fn time(mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
@@ -0,0 +1,242 @@
+use bevy::prelude::*;
+
+use crate::{
+    lane::Lane,
+    mana::Mana,
+    status::{StatusEffectBlueprint, StatusEffectKind, StatusEffects},
+    structure::Nexus,
+    subject::Subject,
+    unit::{DamageKind, DamageSource, Shield},
+    weapon::{spawn_arrow, ProjectileBehaviour, WeaponAssets},
+    AppState, Kingdom, SKY_HEIGHT,
+};
+
+const ARROW_RAIN_COUNT: usize = 16;
+const ARROW_RAIN_SPREAD: f32 = 3.0;
+const ARROW_RAIN_SPEED: f32 = 15.0;
+const RALLY_EFFECT: StatusEffectBlueprint = StatusEffectBlueprint {
+    kind: StatusEffectKind::Haste(1.5),
+    seconds: 5.0,
+};
+const NEXUS_SHIELD_SECONDS: f32 = 8.0;
+
+pub struct AbilityPlugin;
+
+impl Plugin for AbilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Abilities>()
+            .add_event::<CastEvent>()
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(reset_abilities))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(cast_on_cast_event)
+                    .with_system(tick_cooldowns.after(cast_on_cast_event)),
+            );
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Ability {
+    ArrowRain,
+    Rally,
+    NexusShield,
+}
+
+impl Ability {
+    pub const ALL: [Ability; 3] = [Ability::ArrowRain, Ability::Rally, Ability::NexusShield];
+
+    pub fn cost(&self) -> u32 {
+        match self {
+            Ability::ArrowRain => 20,
+            Ability::Rally => 15,
+            Ability::NexusShield => 25,
+        }
+    }
+
+    fn cooldown_seconds(&self) -> f32 {
+        match self {
+            Ability::ArrowRain => 20.0,
+            Ability::Rally => 30.0,
+            Ability::NexusShield => 45.0,
+        }
+    }
+
+    pub fn is_targeted(&self) -> bool {
+        matches!(self, Ability::ArrowRain)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Ability::ArrowRain => "Volley",
+            Ability::Rally => "Rally",
+            Ability::NexusShield => "Shield",
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct KingdomAbilities {
+    arrow_rain: Option<Timer>,
+    rally: Option<Timer>,
+    nexus_shield: Option<Timer>,
+}
+
+impl KingdomAbilities {
+    fn cooldown(&self, ability: Ability) -> &Option<Timer> {
+        match ability {
+            Ability::ArrowRain => &self.arrow_rain,
+            Ability::Rally => &self.rally,
+            Ability::NexusShield => &self.nexus_shield,
+        }
+    }
+
+    fn cooldown_mut(&mut self, ability: Ability) -> &mut Option<Timer> {
+        match ability {
+            Ability::ArrowRain => &mut self.arrow_rain,
+            Ability::Rally => &mut self.rally,
+            Ability::NexusShield => &mut self.nexus_shield,
+        }
+    }
+
+    pub fn remaining_seconds(&self, ability: Ability) -> Option<f32> {
+        self.cooldown(ability).as_ref().map(Timer::remaining_secs)
+    }
+
+    pub fn is_ready(&self, ability: Ability) -> bool {
+        self.cooldown(ability).is_none()
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct Abilities {
+    elven: KingdomAbilities,
+    monster: KingdomAbilities,
+}
+
+impl Abilities {
+    pub fn get(&self, kingdom: Kingdom) -> &KingdomAbilities {
+        match kingdom {
+            Kingdom::Elven => &self.elven,
+            Kingdom::Monster => &self.monster,
+        }
+    }
+
+    fn get_mut(&mut self, kingdom: Kingdom) -> &mut KingdomAbilities {
+        match kingdom {
+            Kingdom::Elven => &mut self.elven,
+            Kingdom::Monster => &mut self.monster,
+        }
+    }
+}
+
+pub struct CastEvent {
+    pub ability: Ability,
+    pub kingdom: Kingdom,
+    pub target: Option<Vec2>,
+}
+
+impl CastEvent {
+    pub fn new(ability: Ability, kingdom: Kingdom) -> Self {
+        Self {
+            ability,
+            kingdom,
+            target: None,
+        }
+    }
+
+    pub fn targeted(ability: Ability, kingdom: Kingdom, target: Vec2) -> Self {
+        Self {
+            ability,
+            kingdom,
+            target: Some(target),
+        }
+    }
+}
+
+fn reset_abilities(mut abilities: ResMut<Abilities>) {
+    *abilities = Abilities::default();
+}
+
+fn cast_on_cast_event(
+    mut events: EventReader<CastEvent>,
+    mut abilities: ResMut<Abilities>,
+    mut mana: ResMut<Mana>,
+    mut subject_query: Query<(&Kingdom, &mut StatusEffects), With<Subject>>,
+    nexus_query: Query<(Entity, &Kingdom), With<Nexus>>,
+    assets: Res<WeaponAssets>,
+    mut commands: Commands,
+) {
+    for event in events.iter() {
+        let kingdom_abilities = abilities.get_mut(event.kingdom);
+        if !kingdom_abilities.is_ready(event.ability) {
+            continue;
+        }
+        if event.ability.is_targeted() && event.target.is_none() {
+            continue;
+        }
+        if !mana.spend(event.ability.cost(), event.kingdom) {
+            continue;
+        }
+
+        *kingdom_abilities.cooldown_mut(event.ability) = Some(Timer::from_seconds(
+            event.ability.cooldown_seconds(),
+            TimerMode::Once,
+        ));
+
+        let source = DamageSource::new(None, event.kingdom);
+        match (event.ability, event.target) {
+            (Ability::ArrowRain, Some(target)) => {
+                let lane = Lane::closest(target.y);
+                for _ in 0..ARROW_RAIN_COUNT {
+                    let offset = (fastrand::f32() - 0.5) * 2.0 * ARROW_RAIN_SPREAD;
+                    let height = SKY_HEIGHT + fastrand::f32() * ARROW_RAIN_SPREAD;
+                    spawn_arrow(
+                        Vec3::new(target.x + offset, lane.ground() + height, lane.depth()),
+                        Vec2::new(0.0, -ARROW_RAIN_SPEED),
+                        source,
+                        1,
+                        DamageKind::Pierce,
+                        None,
+                        0.0,
+                        ProjectileBehaviour::Sticking,
+                        lane,
+                        &assets,
+                        &mut commands,
+                    );
+                }
+            }
+            (Ability::Rally, _) => {
+                for (kingdom, mut status_effects) in &mut subject_query {
+                    if *kingdom == event.kingdom {
+                        status_effects.apply(&RALLY_EFFECT, source);
+                    }
+                }
+            }
+            (Ability::NexusShield, _) => {
+                for (entity, kingdom) in &nexus_query {
+                    if *kingdom == event.kingdom {
+                        commands
+                            .entity(entity)
+                            .insert(Shield::new(NEXUS_SHIELD_SECONDS));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn tick_cooldowns(mut abilities: ResMut<Abilities>, time: Res<Time>) {
+    for kingdom in [Kingdom::Elven, Kingdom::Monster] {
+        let kingdom_abilities = abilities.get_mut(kingdom);
+        for ability in Ability::ALL {
+            let cooldown = kingdom_abilities.cooldown_mut(ability);
+            if let Some(timer) = cooldown {
+                timer.tick(time.delta());
+                if timer.finished() {
+                    *cooldown = None;
+                }
+            }
+        }
+    }
+}
@@ -1,39 +1,118 @@
 use bevy::prelude::*;
 
 use crate::{
+    lane::Lane,
     recruitment::{Coins, RecruitmentEvent},
-    subject::content::{SubjectBlueprint, GOBLIN_WARRIOR},
+    structure::upgrade::{NexusUpgrade, NexusUpgrades, UpgradeEvent, UpgradeLevels},
+    subject::{
+        content::{
+            SubjectBlueprint, GOBLIN_BAT_RIDER, GOBLIN_BOMB_THROWER, GOBLIN_OGRE, GOBLIN_SHAMAN,
+            GOBLIN_WARLORD, GOBLIN_WARRIOR, GOBLIN_WOLF_RIDER,
+        },
+        hero::Heroes,
+    },
     Kingdom,
 };
 
+// repeated entries are recruited more often
+const RECRUITMENT_POOL: [&SubjectBlueprint; 9] = [
+    &GOBLIN_WARRIOR,
+    &GOBLIN_WARRIOR,
+    &GOBLIN_WARRIOR,
+    &GOBLIN_BOMB_THROWER,
+    &GOBLIN_SHAMAN,
+    &GOBLIN_OGRE,
+    &GOBLIN_BAT_RIDER,
+    &GOBLIN_WOLF_RIDER,
+    &GOBLIN_WARLORD,
+];
+const UPGRADE_PROBABILITY: f32 = 0.1;
+
 pub struct AiPlugin;
 
 impl Plugin for AiPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(AiState::new(&GOBLIN_WARRIOR))
-            .add_system(recruit_if_affordable);
+        app.insert_resource(AiState::new(AiGoal::Recruit(&GOBLIN_WARRIOR)))
+            .add_system(spend_if_affordable);
     }
 }
 
+#[derive(Clone, Copy)]
+enum AiGoal {
+    Recruit(&'static SubjectBlueprint),
+    Upgrade(NexusUpgrade),
+}
+
 #[derive(Resource)]
 struct AiState {
-    blueprint: &'static SubjectBlueprint,
+    goal: AiGoal,
 }
 
 impl AiState {
-    fn new(blueprint: &'static SubjectBlueprint) -> Self {
-        Self { blueprint }
+    fn new(goal: AiGoal) -> Self {
+        Self { goal }
+    }
+
+    fn pick_next(&mut self, levels: &UpgradeLevels, has_hero: bool) {
+        if fastrand::f32() < UPGRADE_PROBABILITY {
+            let upgrade = NexusUpgrade::ALL[fastrand::usize(..NexusUpgrade::ALL.len())];
+            if levels.cost(upgrade).is_some() {
+                self.goal = AiGoal::Upgrade(upgrade);
+                return;
+            }
+        }
+        // only one hero per kingdom can be recruited
+        let pool: Vec<_> = RECRUITMENT_POOL
+            .into_iter()
+            .filter(|blueprint| !(has_hero && blueprint.hero))
+            .collect();
+        self.goal = AiGoal::Recruit(pool[fastrand::usize(..pool.len())]);
     }
 }
 
-fn recruit_if_affordable(
-    state: Res<AiState>,
+fn spend_if_affordable(
+    mut state: ResMut<AiState>,
     coins: Res<Coins>,
-    mut events: EventWriter<RecruitmentEvent>,
+    upgrades: Res<NexusUpgrades>,
+    heroes: Res<Heroes>,
+    mut recruitment_events: EventWriter<RecruitmentEvent>,
+    mut upgrade_events: EventWriter<UpgradeEvent>,
 ) {
+    let levels = upgrades.get(Kingdom::Monster);
     let mut kingdom_coins = coins.get(Kingdom::Monster) as u32;
-    while kingdom_coins >= state.blueprint.value {
-        events.send(RecruitmentEvent::new(state.blueprint, Kingdom::Monster));
-        kingdom_coins -= state.blueprint.value;
+    let mut has_hero = heroes.get(Kingdom::Monster).blueprint().is_some();
+    loop {
+        match state.goal {
+            AiGoal::Recruit(blueprint) if blueprint.hero && has_hero => {
+                state.pick_next(levels, has_hero);
+            }
+            AiGoal::Recruit(blueprint) => {
+                if kingdom_coins < blueprint.value {
+                    break;
+                }
+                recruitment_events.send(RecruitmentEvent::new(
+                    blueprint,
+                    Lane::random(),
+                    Kingdom::Monster,
+                ));
+                kingdom_coins -= blueprint.value;
+                // heroes are only registered once the event is handled
+                has_hero |= blueprint.hero;
+                state.pick_next(levels, has_hero);
+            }
+            AiGoal::Upgrade(upgrade) => {
+                let Some(cost) = levels.cost(upgrade) else {
+                    state.pick_next(levels, has_hero);
+                    continue;
+                };
+                if kingdom_coins < cost {
+                    break;
+                }
+                upgrade_events.send(UpgradeEvent::new(upgrade, Kingdom::Monster));
+                state.pick_next(levels, has_hero);
+                // levels are only updated once the event is handled
+                break;
+            }
+        }
     }
 }
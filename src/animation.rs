@@ -41,6 +41,10 @@ impl AnimationPlayer {
         self.reset = true;
     }
 
+    pub fn sprite_entity(&self) -> Entity {
+        self.sprite_entity
+    }
+
     pub fn is_finished(&self) -> bool {
         matches!(self.mode, AnimationMode::Once) && self.index == (self.length - 1) && !self.reset
     }
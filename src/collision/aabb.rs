@@ -0,0 +1,132 @@
+use bevy::{prelude::*, time::FixedTimestep, transform::TransformSystem, utils::HashMap};
+
+use super::{
+    overlap::{find_intersections, Bounds},
+    KingdomGroups,
+};
+
+// a constant step keeps movement independent of the frame rate
+const TIME_STEP: f32 = 1.0 / 60.0;
+
+#[derive(SystemLabel)]
+struct IntegrateVelocities;
+
+pub struct CollisionPlugin;
+
+impl Plugin for CollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CollisionContext>()
+            .add_system_set_to_stage(
+                CoreStage::PostUpdate,
+                SystemSet::new()
+                    .with_run_criteria(FixedTimestep::step(TIME_STEP as f64))
+                    .with_system(
+                        integrate_velocities
+                            .label(IntegrateVelocities)
+                            .before(TransformSystem::TransformPropagate),
+                    ),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                update_intersections.after(IntegrateVelocities),
+            );
+    }
+}
+
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub enum RigidBody {
+    Fixed,
+    KinematicVelocityBased,
+}
+
+#[derive(Component, Clone, Copy)]
+pub struct Collider {
+    half_extents: Vec2,
+}
+
+impl Collider {
+    pub fn cuboid(half_width: f32, half_height: f32) -> Self {
+        Self {
+            half_extents: Vec2::new(half_width, half_height),
+        }
+    }
+
+    pub fn ball(radius: f32) -> Self {
+        Self {
+            half_extents: Vec2::splat(radius),
+        }
+    }
+}
+
+#[derive(Component, Clone, Copy, Default)]
+pub struct Velocity {
+    pub linvel: Vec2,
+}
+
+impl Velocity {
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn linear(linvel: Vec2) -> Self {
+        Self { linvel }
+    }
+}
+
+#[derive(Bundle)]
+pub struct ColliderBundle {
+    collider: Collider,
+    groups: KingdomGroups,
+}
+
+impl ColliderBundle {
+    pub fn new(collider: Collider, groups: KingdomGroups) -> Self {
+        Self { collider, groups }
+    }
+}
+
+// overlaps found at the end of the previous frame
+#[derive(Resource, Default)]
+pub struct CollisionContext {
+    intersections: HashMap<Entity, Vec<Entity>>,
+}
+
+pub fn intersections_with(
+    collider: Entity,
+    context: &CollisionContext,
+) -> impl Iterator<Item = Entity> + '_ {
+    context
+        .intersections
+        .get(&collider)
+        .into_iter()
+        .flatten()
+        .copied()
+}
+
+fn integrate_velocities(mut query: Query<(&mut Transform, &Velocity, &RigidBody)>) {
+    for (mut transform, velocity, rigid_body) in &mut query {
+        if *rigid_body == RigidBody::KinematicVelocityBased {
+            transform.translation += (velocity.linvel * TIME_STEP).extend(0.0);
+        }
+    }
+}
+
+fn update_intersections(
+    query: Query<(Entity, &Transform, &Collider, &KingdomGroups, &RigidBody)>,
+    mut context: ResMut<CollisionContext>,
+) {
+    let bounds = query
+        .iter()
+        .map(|(entity, transform, collider, groups, rigid_body)| {
+            let center = transform.translation.truncate();
+            Bounds {
+                entity,
+                min: center - collider.half_extents,
+                max: center + collider.half_extents,
+                groups: *groups,
+                is_fixed: *rigid_body == RigidBody::Fixed,
+            }
+        })
+        .collect();
+    context.intersections = find_intersections(bounds);
+}
@@ -0,0 +1,52 @@
+#[cfg(not(feature = "rapier"))]
+mod aabb;
+// kept out of the feature gate so its tests run with the default features
+#[cfg_attr(feature = "rapier", allow(dead_code))]
+mod overlap;
+#[cfg(feature = "rapier")]
+mod rapier;
+
+use bevy::prelude::*;
+
+use crate::Kingdom;
+
+#[cfg(not(feature = "rapier"))]
+pub use self::aabb::{
+    intersections_with, Collider, ColliderBundle, CollisionContext, CollisionPlugin, RigidBody,
+    Velocity,
+};
+#[cfg(feature = "rapier")]
+pub use self::rapier::{
+    intersections_with, Collider, ColliderBundle, CollisionContext, CollisionPlugin, RigidBody,
+    Velocity,
+};
+
+// each kingdom is a collision group, two colliders overlap only if both accept the other
+#[derive(Component, Clone, Copy)]
+pub struct KingdomGroups {
+    memberships: u32,
+    filters: u32,
+}
+
+impl KingdomGroups {
+    pub fn new(kingdom: Kingdom) -> Self {
+        Self {
+            memberships: group(kingdom),
+            filters: group(Kingdom::Elven) | group(Kingdom::Monster),
+        }
+    }
+
+    pub fn rivals(kingdom: Kingdom) -> Self {
+        Self {
+            memberships: group(kingdom),
+            filters: group(kingdom.rival()),
+        }
+    }
+}
+
+fn group(kingdom: Kingdom) -> u32 {
+    match kingdom {
+        Kingdom::Elven => 1 << 0,
+        Kingdom::Monster => 1 << 1,
+    }
+}
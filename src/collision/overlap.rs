@@ -0,0 +1,127 @@
+use bevy::{prelude::*, utils::HashMap};
+
+use super::KingdomGroups;
+
+pub struct Bounds {
+    pub entity: Entity,
+    pub min: Vec2,
+    pub max: Vec2,
+    pub groups: KingdomGroups,
+    pub is_fixed: bool,
+}
+
+impl KingdomGroups {
+    fn interacts_with(&self, other: &KingdomGroups) -> bool {
+        self.memberships & other.filters != 0 && other.memberships & self.filters != 0
+    }
+}
+
+// sweep and prune along x, since the battlefield is far wider than it is tall
+pub fn find_intersections(mut bounds: Vec<Bounds>) -> HashMap<Entity, Vec<Entity>> {
+    // ties are broken by entity so that the results are deterministic
+    bounds.sort_unstable_by(|a, b| a.min.x.total_cmp(&b.min.x).then(a.entity.cmp(&b.entity)));
+
+    let mut intersections: HashMap<Entity, Vec<Entity>> = HashMap::default();
+    for (i, a) in bounds.iter().enumerate() {
+        for b in &bounds[i + 1..] {
+            if b.min.x > a.max.x {
+                break;
+            }
+            // like sensors, fixed colliders never overlap each other
+            if (a.is_fixed && b.is_fixed)
+                || b.min.y > a.max.y
+                || a.min.y > b.max.y
+                || !a.groups.interacts_with(&b.groups)
+            {
+                continue;
+            }
+            intersections.entry(a.entity).or_default().push(b.entity);
+            intersections.entry(b.entity).or_default().push(a.entity);
+        }
+    }
+    intersections
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Kingdom;
+
+    use super::*;
+
+    fn bounds(id: u32, center: Vec2, groups: KingdomGroups, is_fixed: bool) -> Bounds {
+        Bounds {
+            entity: Entity::from_raw(id),
+            min: center - Vec2::ONE,
+            max: center + Vec2::ONE,
+            groups,
+            is_fixed,
+        }
+    }
+
+    fn overlaps(intersections: &HashMap<Entity, Vec<Entity>>, a: u32, b: u32) -> bool {
+        intersections
+            .get(&Entity::from_raw(a))
+            .is_some_and(|entities| entities.contains(&Entity::from_raw(b)))
+    }
+
+    #[test]
+    fn overlapping_colliders_intersect_both_ways() {
+        let elven = KingdomGroups::new(Kingdom::Elven);
+        let monster = KingdomGroups::new(Kingdom::Monster);
+        let intersections = find_intersections(vec![
+            bounds(0, Vec2::new(0.0, 0.0), elven, false),
+            bounds(1, Vec2::new(1.5, 0.5), monster, false),
+            bounds(2, Vec2::new(5.0, 0.0), monster, false),
+        ]);
+
+        assert!(overlaps(&intersections, 0, 1));
+        assert!(overlaps(&intersections, 1, 0));
+        assert!(!overlaps(&intersections, 0, 2));
+        assert!(!overlaps(&intersections, 1, 2));
+    }
+
+    #[test]
+    fn fixed_colliders_never_intersect_each_other() {
+        let elven = KingdomGroups::new(Kingdom::Elven);
+        let monster = KingdomGroups::new(Kingdom::Monster);
+        let intersections = find_intersections(vec![
+            bounds(0, Vec2::ZERO, elven, true),
+            bounds(1, Vec2::ZERO, monster, true),
+            bounds(2, Vec2::ZERO, monster, false),
+        ]);
+
+        assert!(!overlaps(&intersections, 0, 1));
+        assert!(overlaps(&intersections, 0, 2));
+        assert!(overlaps(&intersections, 1, 2));
+    }
+
+    #[test]
+    fn colliders_separated_along_y_do_not_intersect() {
+        let elven = KingdomGroups::new(Kingdom::Elven);
+        let intersections = find_intersections(vec![
+            bounds(0, Vec2::new(0.0, 0.0), elven, false),
+            bounds(1, Vec2::new(0.0, 2.5), elven, false),
+            bounds(2, Vec2::new(0.5, -2.5), elven, false),
+        ]);
+
+        assert!(intersections.is_empty());
+    }
+
+    #[test]
+    fn rival_groups_only_intersect_the_other_kingdom() {
+        let arrow = KingdomGroups::rivals(Kingdom::Elven);
+        let elven = KingdomGroups::new(Kingdom::Elven);
+        let monster = KingdomGroups::new(Kingdom::Monster);
+        let intersections = find_intersections(vec![
+            bounds(0, Vec2::ZERO, arrow, false),
+            bounds(1, Vec2::ZERO, elven, false),
+            bounds(2, Vec2::ZERO, monster, false),
+            bounds(3, Vec2::ZERO, arrow, false),
+        ]);
+
+        assert!(!overlaps(&intersections, 0, 1));
+        assert!(overlaps(&intersections, 0, 2));
+        assert!(!overlaps(&intersections, 0, 3));
+        assert!(overlaps(&intersections, 1, 2));
+    }
+}
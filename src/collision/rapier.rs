@@ -1,27 +1,46 @@
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
+pub use bevy_rapier2d::prelude::{
+    Collider, RapierContext as CollisionContext, RigidBody, Velocity,
+};
+
+use super::KingdomGroups;
+
+pub struct CollisionPlugin;
+
+impl Plugin for CollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(RapierPhysicsPlugin::<NoUserData>::default());
+    }
+}
+
 #[derive(Bundle)]
 pub struct ColliderBundle {
     collider: Collider,
     sensor: Sensor,
     active_collision_types: ActiveCollisionTypes,
+    collision_groups: CollisionGroups,
 }
 
 impl ColliderBundle {
-    pub fn new(collider: Collider) -> Self {
+    pub fn new(collider: Collider, groups: KingdomGroups) -> Self {
         Self {
             collider,
             sensor: Sensor,
             active_collision_types: ActiveCollisionTypes::KINEMATIC_KINEMATIC
                 | ActiveCollisionTypes::KINEMATIC_STATIC,
+            collision_groups: CollisionGroups::new(
+                Group::from_bits_truncate(groups.memberships),
+                Group::from_bits_truncate(groups.filters),
+            ),
         }
     }
 }
 
 pub fn intersections_with(
     collider: Entity,
-    context: &RapierContext,
+    context: &CollisionContext,
 ) -> impl Iterator<Item = Entity> + '_ {
     context
         .intersections_with(collider)
@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+pub struct CursorPlugin;
+
+impl Plugin for CursorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CursorPosition>()
+            .add_system_to_stage(CoreStage::PreUpdate, update_cursor_position);
+    }
+}
+
+#[derive(Component)]
+pub struct MainCamera;
+
+#[derive(Resource, Default, Deref)]
+pub struct CursorPosition(Option<Vec2>);
+
+fn update_cursor_position(
+    query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    windows: Res<Windows>,
+    mut cursor: ResMut<CursorPosition>,
+) {
+    let Ok((camera, camera_transform)) = query.get_single() else {
+        return;
+    };
+
+    cursor.0 = windows
+        .get_primary()
+        .and_then(Window::cursor_position)
+        .and_then(|position| camera.viewport_to_world(camera_transform, position))
+        .map(|ray| ray.origin.truncate());
+}
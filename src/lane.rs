@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+
+use crate::{palette, WORLD_EXTENSION};
+
+pub const LANE_COUNT: usize = 3;
+const LANE_SPACING: f32 = 2.5;
+const LANE_DEPTH: f32 = 2.0;
+const LANE_MARKING_HEIGHT: f32 = 0.1;
+
+pub struct LanePlugin;
+
+impl Plugin for LanePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_startup_system(spawn_lane_markings);
+    }
+}
+
+// structures and nexuses have no lane and take part in all of them
+#[derive(Component, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Lane(usize);
+
+impl Lane {
+    pub const ALL: [Lane; LANE_COUNT] = [Lane(0), Lane(1), Lane(2)];
+
+    pub fn index(&self) -> usize {
+        self.0
+    }
+
+    pub fn ground(&self) -> f32 {
+        -LANE_SPACING * self.0 as f32
+    }
+
+    pub fn depth(&self) -> f32 {
+        LANE_DEPTH * self.0 as f32
+    }
+
+    pub fn closest(y: f32) -> Lane {
+        let index = (-y / LANE_SPACING)
+            .round()
+            .clamp(0.0, (LANE_COUNT - 1) as f32);
+        Lane(index as usize)
+    }
+
+    pub fn random() -> Lane {
+        Lane::ALL[fastrand::usize(..LANE_COUNT)]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self.0 {
+            0 => "Top",
+            1 => "Mid",
+            _ => "Bot",
+        }
+    }
+}
+
+pub fn shares_lane(a: Option<&Lane>, b: Option<&Lane>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a == b,
+        _ => true,
+    }
+}
+
+// laneless entities stand on the lowest ground and reach up through every lane
+pub fn span_all_lanes(size: Vec2) -> (Vec2, f32) {
+    let lowest_ground = Lane::ALL[LANE_COUNT - 1].ground();
+    let size = Vec2::new(size.x, size.y - lowest_ground);
+    (size, lowest_ground + size.y / 2.0)
+}
+
+fn spawn_lane_markings(mut commands: Commands) {
+    // the top lane is marked by the edge of the ground
+    for lane in Lane::ALL.into_iter().skip(1) {
+        commands.spawn((
+            Name::new("Lane marking"),
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette::LIGHT_GREEN,
+                    custom_size: Some(Vec2::new(WORLD_EXTENSION * 2.0, LANE_MARKING_HEIGHT)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(0.0, lane.ground(), lane.depth() - 0.5),
+                ..default()
+            },
+        ));
+    }
+}
@@ -1,30 +1,61 @@
 #![allow(clippy::too_many_arguments, clippy::type_complexity)]
 
+mod ability;
 mod ai;
 mod animation;
 mod collision;
+mod cursor;
+mod lane;
+mod mana;
 mod palette;
 mod recruitment;
+mod research;
+mod settings;
+mod spatial;
+mod status;
 mod structure;
 mod subject;
 mod ui;
 mod unit;
 mod weapon;
+mod wind;
 
 use bevy::{
     asset::Asset, core_pipeline::clear_color::ClearColorConfig, prelude::*,
     render::camera::ScalingMode, sprite::Anchor,
 };
 
+pub use self::{
+    lane::Lane,
+    recruitment::{Economies, Economy},
+    settings::MatchSettings,
+    spatial::{SpatialEntry, SpatialIndex},
+};
+
 use self::{
-    ai::AiPlugin, animation::AnimationPlugin, recruitment::RecruitmentPlugin,
-    structure::StructurePlugin, subject::SubjectPlugin, ui::UiPlugin, unit::UnitPlugin,
+    ability::AbilityPlugin,
+    ai::AiPlugin,
+    animation::AnimationPlugin,
+    collision::CollisionPlugin,
+    cursor::{CursorPlugin, MainCamera},
+    lane::LanePlugin,
+    mana::ManaPlugin,
+    recruitment::RecruitmentPlugin,
+    research::ResearchPlugin,
+    spatial::SpatialPlugin,
+    status::StatusPlugin,
+    structure::StructurePlugin,
+    subject::SubjectPlugin,
+    ui::UiPlugin,
+    unit::UnitPlugin,
     weapon::WeaponPlugin,
+    wind::WindPlugin,
 };
 
 // perfect pixel art: 360.0 / 22.5 = 16.0
-const SKY_HEIGHT: f32 = 15.0;
-const GROUND_HEIGHT: f32 = 7.5;
+// the ground is tall enough that the lowest lane clears the bottom ui
+const SKY_HEIGHT: f32 = 12.5;
+const GROUND_HEIGHT: f32 = 10.0;
 const WORLD_EXTENSION: f32 = 20.0;
 const GRAVITY_ACCELERATION: f32 = 9.8;
 const CAMERA_HEIGHT: f32 = (SKY_HEIGHT - GROUND_HEIGHT) / 2.0;
@@ -35,14 +66,24 @@ pub struct AppPlugin;
 
 impl Plugin for AppPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugin(AiPlugin)
+        app.init_resource::<MatchSettings>()
+            .add_plugin(AbilityPlugin)
+            .add_plugin(AiPlugin)
             .add_plugin(AnimationPlugin)
+            .add_plugin(CollisionPlugin)
+            .add_plugin(CursorPlugin)
+            .add_plugin(LanePlugin)
+            .add_plugin(ManaPlugin)
             .add_plugin(RecruitmentPlugin)
+            .add_plugin(ResearchPlugin)
+            .add_plugin(SpatialPlugin)
+            .add_plugin(StatusPlugin)
             .add_plugin(StructurePlugin)
             .add_plugin(SubjectPlugin)
             .add_plugin(UiPlugin)
             .add_plugin(UnitPlugin)
             .add_plugin(WeaponPlugin)
+            .add_plugin(WindPlugin)
             .add_state(AppState::Menu)
             .add_startup_system(setup);
     }
@@ -85,17 +126,20 @@ impl<T: Asset> KingdomHandle<T> {
 
 fn setup(mut commands: Commands) {
     // camera
-    commands.spawn(Camera2dBundle {
-        camera_2d: Camera2d {
-            clear_color: ClearColorConfig::Custom(palette::LIGHT_CYAN),
-        },
-        projection: OrthographicProjection {
-            scaling_mode: ScalingMode::FixedVertical(CAMERA_SIZE),
+    commands.spawn((
+        Camera2dBundle {
+            camera_2d: Camera2d {
+                clear_color: ClearColorConfig::Custom(palette::LIGHT_CYAN),
+            },
+            projection: OrthographicProjection {
+                scaling_mode: ScalingMode::FixedVertical(CAMERA_SIZE),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, CAMERA_HEIGHT, 99.9),
             ..default()
         },
-        transform: Transform::from_xyz(0.0, CAMERA_HEIGHT, 99.9),
-        ..default()
-    });
+        MainCamera,
+    ));
     // background
     commands.spawn((
         Name::new("Ground"),
@@ -4,32 +4,36 @@ use bevy::{
 };
 use bevy_inspector_egui::WorldInspectorPlugin;
 use bevy_kira_audio::prelude::*;
+#[cfg(feature = "rapier")]
 use bevy_rapier2d::prelude::*;
-use kingdoms_arena::AppPlugin;
+use kingdoms_arena::{AppPlugin, MatchSettings};
 
 fn main() {
-    App::new()
-        .add_plugins(
-            DefaultPlugins
-                .set(WindowPlugin {
-                    window: WindowDescriptor {
-                        width: 1280.0,
-                        height: 720.0,
-                        ..default()
-                    },
+    let mut app = App::new();
+    app.add_plugins(
+        DefaultPlugins
+            .set(WindowPlugin {
+                window: WindowDescriptor {
+                    width: 1280.0,
+                    height: 720.0,
                     ..default()
-                })
-                .set(ImagePlugin::default_nearest()),
-        )
-        .add_plugin(AudioPlugin)
-        .add_plugin(RapierPhysicsPlugin::<NoUserData>::default())
-        .add_plugin(RapierDebugRenderPlugin {
-            mode: DebugRenderMode::COLLIDER_SHAPES,
-            ..default()
-        })
-        .add_plugin(WorldInspectorPlugin::new())
-        .add_plugin(FrameTimeDiagnosticsPlugin)
-        .add_plugin(EntityCountDiagnosticsPlugin)
-        .add_plugin(AppPlugin)
-        .run();
+                },
+                ..default()
+            })
+            .set(ImagePlugin::default_nearest()),
+    )
+    .add_plugin(AudioPlugin)
+    .add_plugin(WorldInspectorPlugin::new())
+    .add_plugin(FrameTimeDiagnosticsPlugin)
+    .add_plugin(EntityCountDiagnosticsPlugin)
+    .insert_resource(MatchSettings::default())
+    .add_plugin(AppPlugin);
+
+    #[cfg(feature = "rapier")]
+    app.add_plugin(RapierDebugRenderPlugin {
+        mode: DebugRenderMode::COLLIDER_SHAPES,
+        ..default()
+    });
+
+    app.run();
 }
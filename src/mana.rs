@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+
+use crate::{
+    unit::{DeathEvent, Frontlines, ResolveDamage},
+    AppState, Kingdom,
+};
+
+const MANA_CAP: f32 = 100.0;
+const MANA_PER_KILL: f32 = 2.0;
+const MANA_PER_SECOND_PAST_MIDPOINT: f32 = 1.0;
+
+pub struct ManaPlugin;
+
+impl Plugin for ManaPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Mana>()
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(reset_mana))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(generate_mana_past_midpoint)
+                    .with_system(generate_mana_from_kills.after(ResolveDamage)),
+            );
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct Mana {
+    elven: f32,
+    monster: f32,
+}
+
+impl Mana {
+    pub fn get(&self, kingdom: Kingdom) -> f32 {
+        match kingdom {
+            Kingdom::Elven => self.elven,
+            Kingdom::Monster => self.monster,
+        }
+    }
+
+    pub fn set(&mut self, mana: f32, kingdom: Kingdom) {
+        match kingdom {
+            Kingdom::Elven => self.elven = mana,
+            Kingdom::Monster => self.monster = mana,
+        }
+    }
+
+    pub fn add(&mut self, mana: f32, kingdom: Kingdom) {
+        self.set(f32::min(self.get(kingdom) + mana, MANA_CAP), kingdom);
+    }
+
+    pub fn spend(&mut self, amount: u32, kingdom: Kingdom) -> bool {
+        let kingdom_mana = self.get(kingdom);
+        if amount as f32 > kingdom_mana {
+            return false;
+        }
+        self.set(kingdom_mana - amount as f32, kingdom);
+        true
+    }
+}
+
+fn reset_mana(mut mana: ResMut<Mana>) {
+    *mana = Mana::default();
+}
+
+fn generate_mana_past_midpoint(
+    frontlines: Res<Frontlines>,
+    mut mana: ResMut<Mana>,
+    time: Res<Time>,
+) {
+    let amount = MANA_PER_SECOND_PAST_MIDPOINT * time.delta_seconds();
+    if frontlines.own(Kingdom::Elven, None).position > 0.0 {
+        mana.add(amount, Kingdom::Elven);
+    }
+    if frontlines.own(Kingdom::Monster, None).position < 0.0 {
+        mana.add(amount, Kingdom::Monster);
+    }
+}
+
+fn generate_mana_from_kills(mut events: EventReader<DeathEvent>, mut mana: ResMut<Mana>) {
+    for event in events.iter() {
+        if event.killer.kingdom != event.kingdom {
+            mana.add(MANA_PER_KILL, event.killer.kingdom);
+        }
+    }
+}
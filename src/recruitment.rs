@@ -1,17 +1,32 @@
 use bevy::prelude::*;
 
-use crate::{structure::NexusSpawnEvent, subject::content::SubjectBlueprint, AppState, Kingdom};
-
-const COINS_PER_SECOND: f32 = 1.5;
+use crate::{
+    lane::Lane,
+    research::Research,
+    settings::MatchSettings,
+    structure::{
+        upgrade::{NexusUpgrade, NexusUpgrades, INCOME_BONUS_PER_LEVEL},
+        NexusSpawnEvent,
+    },
+    subject::{content::SubjectBlueprint, hero::Heroes, Reward},
+    unit::{DeathEvent, ResolveDamage},
+    AppState, Kingdom,
+};
 
 pub struct RecruitmentPlugin;
 
 impl Plugin for RecruitmentPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<Coins>()
+            .init_resource::<Economies>()
             .add_event::<RecruitmentEvent>()
+            .add_system_set(SystemSet::on_enter(AppState::Game).with_system(load_economies))
             .add_system_set(SystemSet::on_exit(AppState::Game).with_system(reset_coins))
-            .add_system_set(SystemSet::on_update(AppState::Game).with_system(generate_coins))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(generate_coins)
+                    .with_system(reward_kills.after(ResolveDamage)),
+            )
             .add_system(nexus_spawn_on_recruitment_event);
     }
 }
@@ -36,22 +51,116 @@ impl Coins {
             Kingdom::Monster => self.monster = coins,
         }
     }
+
+    pub fn add(&mut self, coins: f32, kingdom: Kingdom) {
+        self.set(self.get(kingdom) + coins, kingdom);
+    }
+}
+
+#[derive(Clone)]
+pub struct Economy {
+    pub base_income: f32,
+    pub bounty_ratio: f32,
+    pub starting_coins: f32,
+    pub coin_cap: f32,
+    pub interest_rate: f32,
+}
+
+impl Default for Economy {
+    fn default() -> Self {
+        Self {
+            base_income: 1.5,
+            bounty_ratio: 0.25,
+            starting_coins: 0.0,
+            coin_cap: f32::INFINITY,
+            interest_rate: 0.0,
+        }
+    }
+}
+
+#[derive(Resource, Default, Clone)]
+pub struct Economies {
+    pub elven: Economy,
+    pub monster: Economy,
+}
+
+impl Economies {
+    pub fn get(&self, kingdom: Kingdom) -> &Economy {
+        match kingdom {
+            Kingdom::Elven => &self.elven,
+            Kingdom::Monster => &self.monster,
+        }
+    }
 }
 
 pub struct RecruitmentEvent {
     pub blueprint: &'static SubjectBlueprint,
+    pub lane: Lane,
     pub kingdom: Kingdom,
 }
 
 impl RecruitmentEvent {
-    pub fn new(blueprint: &'static SubjectBlueprint, kingdom: Kingdom) -> Self {
-        Self { blueprint, kingdom }
+    pub fn new(blueprint: &'static SubjectBlueprint, lane: Lane, kingdom: Kingdom) -> Self {
+        Self {
+            blueprint,
+            lane,
+            kingdom,
+        }
     }
 }
 
-fn generate_coins(mut coins: ResMut<Coins>, time: Res<Time>) {
-    coins.elven += COINS_PER_SECOND * time.delta_seconds();
-    coins.monster += COINS_PER_SECOND * time.delta_seconds();
+fn earn(coins: &mut Coins, amount: f32, economy: &Economy, kingdom: Kingdom) {
+    let room = f32::max(economy.coin_cap - coins.get(kingdom), 0.0);
+    coins.add(f32::min(amount, room), kingdom);
+}
+
+fn load_economies(
+    settings: Res<MatchSettings>,
+    mut economies: ResMut<Economies>,
+    mut coins: ResMut<Coins>,
+) {
+    *economies = settings.economies.clone();
+    coins.elven = economies.elven.starting_coins;
+    coins.monster = economies.monster.starting_coins;
+}
+
+fn generate_coins(
+    mut coins: ResMut<Coins>,
+    economies: Res<Economies>,
+    upgrades: Res<NexusUpgrades>,
+    time: Res<Time>,
+) {
+    for kingdom in [Kingdom::Elven, Kingdom::Monster] {
+        let economy = economies.get(kingdom);
+        let level = upgrades.get(kingdom).get(NexusUpgrade::Income);
+        let income = economy.base_income * (1.0 + INCOME_BONUS_PER_LEVEL * level as f32);
+        let interest = economy.interest_rate * coins.get(kingdom);
+        earn(
+            &mut coins,
+            (income + interest) * time.delta_seconds(),
+            economy,
+            kingdom,
+        );
+    }
+}
+
+fn reward_kills(
+    mut events: EventReader<DeathEvent>,
+    query: Query<&Reward>,
+    mut coins: ResMut<Coins>,
+    economies: Res<Economies>,
+) {
+    for event in events.iter() {
+        let Ok(reward) = query.get(event.entity) else {
+            continue;
+        };
+
+        if event.killer.kingdom != event.kingdom {
+            let economy = economies.get(event.killer.kingdom);
+            let bounty = **reward * economy.bounty_ratio;
+            earn(&mut coins, bounty, economy, event.killer.kingdom);
+        }
+    }
 }
 
 fn reset_coins(mut coins: ResMut<Coins>) {
@@ -62,19 +171,37 @@ fn reset_coins(mut coins: ResMut<Coins>) {
 fn nexus_spawn_on_recruitment_event(
     mut recruitment_events: EventReader<RecruitmentEvent>,
     mut nexus_spawn_events: EventWriter<NexusSpawnEvent>,
+    research: Res<Research>,
+    mut heroes: ResMut<Heroes>,
     mut coins: ResMut<Coins>,
 ) {
     for recruitment_event in recruitment_events.iter() {
+        if !research
+            .get(recruitment_event.kingdom)
+            .is_unlocked(recruitment_event.blueprint)
+        {
+            continue;
+        }
         let kingdom_coins = coins.get(recruitment_event.kingdom) as u32;
         if recruitment_event.blueprint.value > kingdom_coins {
             continue;
         }
+        if recruitment_event.blueprint.hero
+            && !heroes.recruit(
+                recruitment_event.blueprint,
+                recruitment_event.lane,
+                recruitment_event.kingdom,
+            )
+        {
+            continue;
+        }
         coins.set(
             (kingdom_coins - recruitment_event.blueprint.value) as f32,
             recruitment_event.kingdom,
         );
         nexus_spawn_events.send(NexusSpawnEvent::new(
             recruitment_event.blueprint,
+            recruitment_event.lane,
             recruitment_event.kingdom,
         ));
     }
@@ -0,0 +1,212 @@
+use bevy::prelude::*;
+
+use crate::{
+    recruitment::Coins,
+    subject::{content::SubjectBlueprint, SubjectModifiers},
+    AppState, Kingdom,
+};
+
+pub struct ResearchPlugin;
+
+impl Plugin for ResearchPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Research>()
+            .add_event::<ResearchEvent>()
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(reset_research))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(start_research_on_research_event)
+                    .with_system(progress_research.after(start_research_on_research_event)),
+            );
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Tech {
+    FastArchery,
+    SniperArchery,
+    SharpArrowheads,
+    QuickDraw,
+    Fleetfoot,
+    Vitality,
+}
+
+impl Tech {
+    pub const ALL: [Tech; 6] = [
+        Tech::FastArchery,
+        Tech::SniperArchery,
+        Tech::SharpArrowheads,
+        Tech::QuickDraw,
+        Tech::Fleetfoot,
+        Tech::Vitality,
+    ];
+
+    pub fn cost(&self) -> u32 {
+        match self {
+            Tech::FastArchery => 12,
+            Tech::SniperArchery => 10,
+            Tech::SharpArrowheads => 15,
+            Tech::QuickDraw => 15,
+            Tech::Fleetfoot => 10,
+            Tech::Vitality => 20,
+        }
+    }
+
+    fn seconds(&self) -> f32 {
+        match self {
+            Tech::FastArchery => 10.0,
+            Tech::SniperArchery => 8.0,
+            Tech::SharpArrowheads => 15.0,
+            Tech::QuickDraw => 15.0,
+            Tech::Fleetfoot => 10.0,
+            Tech::Vitality => 20.0,
+        }
+    }
+
+    fn requires(&self) -> Option<Tech> {
+        match self {
+            Tech::QuickDraw => Some(Tech::FastArchery),
+            Tech::Vitality => Some(Tech::Fleetfoot),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Tech::FastArchery => "Fast",
+            Tech::SniperArchery => "Sniper",
+            Tech::SharpArrowheads => "Sharp",
+            Tech::QuickDraw => "Draw",
+            Tech::Fleetfoot => "Feet",
+            Tech::Vitality => "Vital",
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct KingdomResearch {
+    researched: Vec<Tech>,
+    current: Option<(Tech, Timer)>,
+}
+
+impl KingdomResearch {
+    pub fn is_researched(&self, tech: Tech) -> bool {
+        self.researched.contains(&tech)
+    }
+
+    pub fn is_unlocked(&self, blueprint: &SubjectBlueprint) -> bool {
+        blueprint
+            .research
+            .is_none_or(|tech| self.is_researched(tech))
+    }
+
+    pub fn is_available(&self, tech: Tech) -> bool {
+        self.current.is_none()
+            && !self.is_researched(tech)
+            && tech
+                .requires()
+                .is_none_or(|required| self.is_researched(required))
+    }
+
+    pub fn progress(&self, tech: Tech) -> Option<f32> {
+        match &self.current {
+            Some((current, timer)) if *current == tech => Some(timer.percent()),
+            _ => None,
+        }
+    }
+
+    pub fn modifiers(&self) -> SubjectModifiers {
+        let mut modifiers = SubjectModifiers::default();
+        for tech in &self.researched {
+            match tech {
+                Tech::SharpArrowheads => modifiers.weapon.arrow_damage_bonus += 1,
+                Tech::QuickDraw => modifiers.weapon.recharge_multiplier *= 0.75,
+                Tech::Fleetfoot => modifiers.speed_multiplier *= 1.2,
+                Tech::Vitality => modifiers.health_bonus += 1,
+                Tech::FastArchery | Tech::SniperArchery => {}
+            }
+        }
+        modifiers
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct Research {
+    elven: KingdomResearch,
+    monster: KingdomResearch,
+}
+
+impl Research {
+    pub fn get(&self, kingdom: Kingdom) -> &KingdomResearch {
+        match kingdom {
+            Kingdom::Elven => &self.elven,
+            Kingdom::Monster => &self.monster,
+        }
+    }
+
+    fn get_mut(&mut self, kingdom: Kingdom) -> &mut KingdomResearch {
+        match kingdom {
+            Kingdom::Elven => &mut self.elven,
+            Kingdom::Monster => &mut self.monster,
+        }
+    }
+}
+
+pub struct ResearchEvent {
+    pub tech: Tech,
+    pub kingdom: Kingdom,
+}
+
+impl ResearchEvent {
+    pub fn new(tech: Tech, kingdom: Kingdom) -> Self {
+        Self { tech, kingdom }
+    }
+}
+
+fn reset_research(mut research: ResMut<Research>) {
+    *research = Research::default();
+}
+
+fn start_research_on_research_event(
+    mut events: EventReader<ResearchEvent>,
+    mut research: ResMut<Research>,
+    mut coins: ResMut<Coins>,
+) {
+    for event in events.iter() {
+        let kingdom_research = research.get_mut(event.kingdom);
+        if !kingdom_research.is_available(event.tech) {
+            continue;
+        }
+        let kingdom_coins = coins.get(event.kingdom) as u32;
+        if event.tech.cost() > kingdom_coins {
+            continue;
+        }
+
+        coins.set((kingdom_coins - event.tech.cost()) as f32, event.kingdom);
+        let timer = Timer::from_seconds(event.tech.seconds(), TimerMode::Once);
+        kingdom_research.current = Some((event.tech, timer));
+    }
+}
+
+fn progress_research(mut research: ResMut<Research>, time: Res<Time>) {
+    let mut is_completed = false;
+    for kingdom in [Kingdom::Elven, Kingdom::Monster] {
+        // ticking the timer alone is not a change anyone has to react to
+        let kingdom_research = research.bypass_change_detection().get_mut(kingdom);
+        let Some((tech, timer)) = &mut kingdom_research.current else {
+            continue;
+        };
+
+        timer.tick(time.delta());
+        if timer.finished() {
+            let tech = *tech;
+            kingdom_research.current = None;
+            kingdom_research.researched.push(tech);
+            is_completed = true;
+        }
+    }
+
+    if is_completed {
+        research.set_changed();
+    }
+}
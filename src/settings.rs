@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+use crate::recruitment::Economies;
+
+// symmetric by default, an asymmetric match overrides one side, e.g. an AI head start:
+// MatchSettings {
+//     economies: Economies {
+//         elven: Economy::default(),
+//         monster: Economy { starting_coins: 10.0, ..default() },
+//     },
+// }
+#[derive(Resource, Default, Clone)]
+pub struct MatchSettings {
+    pub economies: Economies,
+}
@@ -0,0 +1,223 @@
+use bevy::prelude::*;
+
+use crate::{lane::Lane, subject::Flying, unit::Health, Kingdom};
+
+pub struct SpatialPlugin;
+
+impl Plugin for SpatialPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpatialIndex>()
+            .add_system_to_stage(CoreStage::PreUpdate, update_spatial_index);
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct SpatialEntry {
+    pub entity: Entity,
+    pub position: f32,
+    pub lane: Option<Lane>,
+    pub flying: bool,
+}
+
+// units of each kingdom sorted by their x position
+#[derive(Resource, Default)]
+pub struct SpatialIndex {
+    elven: Vec<SpatialEntry>,
+    monster: Vec<SpatialEntry>,
+}
+
+impl SpatialIndex {
+    pub fn get(&self, kingdom: Kingdom) -> &[SpatialEntry] {
+        match kingdom {
+            Kingdom::Elven => &self.elven,
+            Kingdom::Monster => &self.monster,
+        }
+    }
+
+    fn get_mut(&mut self, kingdom: Kingdom) -> &mut Vec<SpatialEntry> {
+        match kingdom {
+            Kingdom::Elven => &mut self.elven,
+            Kingdom::Monster => &mut self.monster,
+        }
+    }
+
+    pub fn rebuild(&mut self, entries: impl Iterator<Item = (Kingdom, SpatialEntry)>) {
+        self.elven.clear();
+        self.monster.clear();
+        for (kingdom, entry) in entries {
+            self.get_mut(kingdom).push(entry);
+        }
+        for entries in [&mut self.elven, &mut self.monster] {
+            entries.sort_unstable_by(|a, b| a.position.total_cmp(&b.position));
+        }
+    }
+
+    pub fn within(
+        &self,
+        kingdom: Kingdom,
+        position: f32,
+        radius: f32,
+    ) -> impl Iterator<Item = &SpatialEntry> {
+        let entries = self.get(kingdom);
+        let start = entries.partition_point(|entry| entry.position < position - radius);
+        let end = entries.partition_point(|entry| entry.position <= position + radius);
+        entries[start..end].iter()
+    }
+
+    // ties are won by the entry behind the position
+    pub fn nearest(
+        &self,
+        kingdom: Kingdom,
+        position: f32,
+        mut filter: impl FnMut(&&SpatialEntry) -> bool,
+    ) -> Option<&SpatialEntry> {
+        let entries = self.get(kingdom);
+        let split = entries.partition_point(|entry| entry.position < position);
+        let before = entries[..split].iter().rev().find(&mut filter);
+        let after = entries[split..].iter().find(&mut filter);
+        match (before, after) {
+            (Some(before), Some(after)) => {
+                if position - before.position <= after.position - position {
+                    Some(before)
+                } else {
+                    Some(after)
+                }
+            }
+            (before, after) => before.or(after),
+        }
+    }
+
+    // entries from the one furthest towards the rival nexus backwards
+    pub fn advancing(&self, kingdom: Kingdom) -> Box<dyn Iterator<Item = &SpatialEntry> + '_> {
+        let entries = self.get(kingdom).iter();
+        match kingdom {
+            Kingdom::Elven => Box::new(entries.rev()),
+            Kingdom::Monster => Box::new(entries),
+        }
+    }
+
+    pub fn most_advanced(
+        &self,
+        kingdom: Kingdom,
+        filter: impl FnMut(&&SpatialEntry) -> bool,
+    ) -> Option<&SpatialEntry> {
+        self.advancing(kingdom).find(filter)
+    }
+}
+
+fn update_spatial_index(
+    query: Query<(Entity, &Transform, &Kingdom, Option<&Lane>, Option<&Flying>), With<Health>>,
+    mut index: ResMut<SpatialIndex>,
+) {
+    index.rebuild(
+        query
+            .iter()
+            .map(|(entity, transform, kingdom, lane, flying)| {
+                let entry = SpatialEntry {
+                    entity,
+                    position: transform.translation.x,
+                    lane: lane.copied(),
+                    flying: flying.is_some(),
+                };
+                (*kingdom, entry)
+            }),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: u32, position: f32, lane: Option<Lane>) -> SpatialEntry {
+        SpatialEntry {
+            entity: Entity::from_raw(id),
+            position,
+            lane,
+            flying: false,
+        }
+    }
+
+    fn index(entries: &[SpatialEntry]) -> SpatialIndex {
+        let mut index = SpatialIndex::default();
+        index.rebuild(entries.iter().map(|entry| (Kingdom::Elven, *entry)));
+        index
+    }
+
+    fn ids<'a>(entries: impl Iterator<Item = &'a SpatialEntry>) -> Vec<u32> {
+        entries.map(|entry| entry.entity.index()).collect()
+    }
+
+    #[test]
+    fn within_includes_both_bounds() {
+        let index = index(&[
+            entry(0, -3.0, None),
+            entry(1, -2.0, None),
+            entry(2, 0.0, None),
+            entry(3, 2.0, None),
+            entry(4, 2.5, None),
+        ]);
+
+        assert_eq!(ids(index.within(Kingdom::Elven, 0.0, 2.0)), [1, 2, 3]);
+        assert!(index.within(Kingdom::Elven, 10.0, 2.0).next().is_none());
+        assert!(index.within(Kingdom::Monster, 0.0, 2.0).next().is_none());
+    }
+
+    #[test]
+    fn nearest_prefers_the_entry_behind_on_ties() {
+        let index = index(&[
+            entry(0, -1.0, None),
+            entry(1, 1.0, None),
+            entry(2, 4.0, None),
+        ]);
+
+        let nearest = |position| {
+            index
+                .nearest(Kingdom::Elven, position, |_| true)
+                .map(|entry| entry.entity.index())
+        };
+        assert_eq!(nearest(0.0), Some(0));
+        assert_eq!(nearest(0.5), Some(1));
+        assert_eq!(nearest(10.0), Some(2));
+        assert_eq!(nearest(-10.0), Some(0));
+    }
+
+    #[test]
+    fn nearest_skips_filtered_entries() {
+        let top = Lane::ALL[0];
+        let bottom = Lane::ALL[2];
+        let index = index(&[entry(0, 0.5, Some(top)), entry(1, 3.0, Some(bottom))]);
+
+        let nearest = index.nearest(Kingdom::Elven, 0.0, |entry| entry.lane == Some(bottom));
+        assert_eq!(nearest.map(|entry| entry.entity.index()), Some(1));
+        assert!(index.nearest(Kingdom::Monster, 0.0, |_| true).is_none());
+    }
+
+    #[test]
+    fn most_advanced_faces_the_rival_nexus() {
+        let mut index = SpatialIndex::default();
+        index.rebuild(
+            [
+                (Kingdom::Elven, entry(0, -5.0, None)),
+                (Kingdom::Elven, entry(1, 3.0, None)),
+                (Kingdom::Elven, entry(2, 1.0, None)),
+                (Kingdom::Monster, entry(3, 4.0, None)),
+                (Kingdom::Monster, entry(4, -1.0, None)),
+                (Kingdom::Monster, entry(5, 6.0, None)),
+            ]
+            .into_iter(),
+        );
+
+        let most_advanced = |kingdom, filter: fn(&&SpatialEntry) -> bool| {
+            index
+                .most_advanced(kingdom, filter)
+                .map(|entry| entry.entity.index())
+        };
+        assert_eq!(most_advanced(Kingdom::Elven, |_| true), Some(1));
+        assert_eq!(most_advanced(Kingdom::Monster, |_| true), Some(4));
+        assert_eq!(
+            most_advanced(Kingdom::Elven, |entry| entry.position < 0.0),
+            Some(0)
+        );
+        assert_eq!(most_advanced(Kingdom::Monster, |_| false), None);
+    }
+}
@@ -0,0 +1,188 @@
+use std::mem;
+
+use bevy::prelude::*;
+
+use crate::{
+    animation::AnimationPlayer,
+    palette,
+    subject::{state::UpdateSubjectState, DamageSubjects},
+    unit::{DamageEvent, DamageKind, DamageSource},
+};
+
+const MAX_STACKS: usize = 3;
+const DAMAGE_INTERVAL_SECONDS: f32 = 1.0;
+
+pub struct StatusPlugin;
+
+impl Plugin for StatusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(tick_status_effects.label(DamageSubjects))
+            .add_system(
+                update_stunned
+                    .after(tick_status_effects)
+                    .before(UpdateSubjectState),
+            )
+            .add_system(tint_affected_sprites.after(tick_status_effects));
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum StatusEffectKind {
+    Slow(f32),
+    Haste(f32),
+    Poison(f32),
+    Burn(f32),
+    // no weapon stuns yet, kept for content to opt into
+    #[allow(dead_code)]
+    Stun,
+}
+
+impl StatusEffectKind {
+    fn tint(&self) -> Color {
+        match self {
+            StatusEffectKind::Slow(_) => palette::LIGHT_CYAN,
+            StatusEffectKind::Haste(_) => palette::LIGHT_PINK,
+            StatusEffectKind::Poison(_) => palette::LIGHT_GREEN,
+            StatusEffectKind::Burn(_) => palette::LIGHT_ORANGE,
+            StatusEffectKind::Stun => palette::LIGHT_YELLOW,
+        }
+    }
+
+    fn damage_per_second(&self) -> Option<f32> {
+        match self {
+            StatusEffectKind::Poison(damage) | StatusEffectKind::Burn(damage) => Some(*damage),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct StatusEffectBlueprint {
+    pub kind: StatusEffectKind,
+    pub seconds: f32,
+}
+
+struct StatusEffect {
+    kind: StatusEffectKind,
+    source: DamageSource,
+    timer: Timer,
+    damage_timer: Timer,
+}
+
+#[derive(Component, Default)]
+pub struct StatusEffects {
+    effects: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    pub fn apply(&mut self, blueprint: &StatusEffectBlueprint, source: DamageSource) {
+        let effect = StatusEffect {
+            kind: blueprint.kind,
+            source,
+            timer: Timer::from_seconds(blueprint.seconds, TimerMode::Once),
+            damage_timer: Timer::from_seconds(DAMAGE_INTERVAL_SECONDS, TimerMode::Repeating),
+        };
+
+        let discriminant = mem::discriminant(&blueprint.kind);
+        let stacks = self
+            .effects
+            .iter()
+            .filter(|effect| mem::discriminant(&effect.kind) == discriminant)
+            .count();
+        if stacks >= MAX_STACKS {
+            // refresh the oldest stack instead of stacking further
+            let index = self
+                .effects
+                .iter()
+                .position(|effect| mem::discriminant(&effect.kind) == discriminant)
+                .unwrap();
+            self.effects.remove(index);
+        }
+        self.effects.push(effect);
+    }
+
+    pub fn speed_multiplier(&self) -> f32 {
+        self.effects
+            .iter()
+            .map(|effect| match effect.kind {
+                StatusEffectKind::Slow(multiplier) | StatusEffectKind::Haste(multiplier) => {
+                    multiplier
+                }
+                StatusEffectKind::Stun => 0.0,
+                _ => 1.0,
+            })
+            .product()
+    }
+
+    fn is_stunned(&self) -> bool {
+        self.effects
+            .iter()
+            .any(|effect| matches!(effect.kind, StatusEffectKind::Stun))
+    }
+
+    fn tint(&self) -> Color {
+        self.effects
+            .last()
+            .map_or(Color::WHITE, |effect| effect.kind.tint())
+    }
+}
+
+#[derive(Component)]
+#[component(storage = "SparseSet")]
+pub struct Stunned;
+
+fn tick_status_effects(
+    mut query: Query<(Entity, &mut StatusEffects)>,
+    mut events: EventWriter<DamageEvent>,
+    time: Res<Time>,
+) {
+    for (entity, mut status_effects) in &mut query {
+        for effect in &mut status_effects.effects {
+            effect.timer.tick(time.delta());
+            let Some(damage_per_second) = effect.kind.damage_per_second() else {
+                continue;
+            };
+
+            effect.damage_timer.tick(time.delta());
+            for _ in 0..effect.damage_timer.times_finished_this_tick() {
+                events.send(DamageEvent::new(
+                    effect.source,
+                    entity,
+                    damage_per_second * DAMAGE_INTERVAL_SECONDS,
+                    DamageKind::Magic,
+                ));
+            }
+        }
+        status_effects
+            .effects
+            .retain(|effect| !effect.timer.finished());
+    }
+}
+
+fn update_stunned(
+    query: Query<(Entity, &StatusEffects, Option<&Stunned>)>,
+    mut commands: Commands,
+) {
+    for (entity, status_effects, stunned) in &query {
+        match (status_effects.is_stunned(), stunned.is_some()) {
+            (true, false) => {
+                commands.entity(entity).insert(Stunned);
+            }
+            (false, true) => {
+                commands.entity(entity).remove::<Stunned>();
+            }
+            _ => {}
+        }
+    }
+}
+
+fn tint_affected_sprites(
+    query: Query<(&AnimationPlayer, &StatusEffects)>,
+    mut sprite_query: Query<&mut TextureAtlasSprite>,
+) {
+    for (player, status_effects) in &query {
+        if let Ok(mut sprite) = sprite_query.get_mut(player.sprite_entity()) {
+            sprite.color = status_effects.tint();
+        }
+    }
+}
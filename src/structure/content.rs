@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+
+use crate::{
+    subject::morale::Inspiring,
+    unit::Armor,
+    weapon::content::{WeaponBlueprint, TOWER_BOW},
+};
+
+pub struct StructureBlueprint {
+    pub name: &'static str,
+    pub value: u32,
+    pub size: Vec2,
+    pub health: u32,
+    pub armor: Armor,
+    pub kind: StructureKind,
+}
+
+pub enum StructureKind {
+    Tower(&'static WeaponBlueprint),
+    Wall { gate: bool },
+    Banner(Inspiring),
+}
+
+pub const TOWER: StructureBlueprint = StructureBlueprint {
+    name: "Tower",
+    value: 15,
+    size: Vec2::new(1.5, 4.0),
+    health: 20,
+    armor: Armor {
+        pierce: 0.5,
+        siege: 2.0,
+        ..Armor::NONE
+    },
+    kind: StructureKind::Tower(&TOWER_BOW),
+};
+
+pub const WALL: StructureBlueprint = StructureBlueprint {
+    name: "Wall",
+    value: 8,
+    size: Vec2::new(1.0, 2.5),
+    health: 200,
+    armor: Armor {
+        pierce: 0.25,
+        slash: 0.5,
+        siege: 2.0,
+        ..Armor::NONE
+    },
+    kind: StructureKind::Wall { gate: false },
+};
+
+pub const GATE: StructureBlueprint = StructureBlueprint {
+    name: "Gate",
+    value: 12,
+    size: Vec2::new(1.0, 2.0),
+    health: 120,
+    armor: Armor {
+        pierce: 0.25,
+        slash: 0.5,
+        siege: 2.0,
+        ..Armor::NONE
+    },
+    kind: StructureKind::Wall { gate: true },
+};
+
+pub const BANNER: StructureBlueprint = StructureBlueprint {
+    name: "Banner",
+    value: 10,
+    size: Vec2::new(0.5, 3.0),
+    health: 10,
+    armor: Armor::NONE,
+    kind: StructureKind::Banner(Inspiring {
+        radius: 6.0,
+        morale_per_second: 10.0,
+    }),
+};
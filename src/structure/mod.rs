@@ -0,0 +1,396 @@
+pub mod content;
+pub mod upgrade;
+
+use bevy::prelude::*;
+use bevy_kira_audio::prelude::*;
+
+use crate::{
+    collision::{
+        intersections_with, Collider, ColliderBundle, CollisionContext, KingdomGroups, RigidBody,
+    },
+    lane::{span_all_lanes, Lane},
+    recruitment::Coins,
+    spatial::SpatialIndex,
+    subject::{
+        content::SubjectBlueprint, morale::Inspiring, state::UpdateSubjectState, Blocked, Climbing,
+        Flying, Reward, SpawnEvent, SpawnSubjects, Subject,
+    },
+    unit::{Armor, DeathEvent, Deflecting, Health, Regeneration, ResolveDamage},
+    weapon::{insert_weapon, Bow, ShootBows, ShotEvent, WeaponModifiers},
+    AppState, Kingdom, KingdomHandle, WORLD_EXTENSION,
+};
+
+use self::{
+    content::{StructureBlueprint, StructureKind},
+    upgrade::NexusUpgradePlugin,
+};
+
+const NEXUS_ARMOR: Armor = Armor {
+    siege: 2.0,
+    ..Armor::NONE
+};
+const NEXUS_INSPIRATION: Inspiring = Inspiring {
+    radius: 4.0,
+    morale_per_second: 25.0,
+};
+// distances from the nexus towards the center of the battlefield
+const SLOT_OFFSETS: [f32; 3] = [3.0, 7.0, 11.0];
+
+pub struct StructurePlugin;
+
+impl Plugin for StructurePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(NexusUpgradePlugin)
+            .init_resource::<StructureAssets>()
+            .add_event::<NexusSpawnEvent>()
+            .add_event::<BuildEvent>()
+            .add_system_set(SystemSet::on_enter(AppState::Game).with_system(spawn_nexuses))
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn_structures))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(build_on_build_event)
+                    .with_system(aim_towers.before(ShootBows))
+                    .with_system(block_subjects.before(UpdateSubjectState))
+                    .with_system(despawn_dead_structures.after(ResolveDamage)),
+            )
+            .add_system(spawn_on_nexus_spawn_event)
+            .add_system(finish_game_on_destroyed_nexus.before(SpawnSubjects));
+    }
+}
+
+#[derive(Resource)]
+struct StructureAssets {
+    spawn_sound: KingdomHandle<AudioSource>,
+}
+
+impl FromWorld for StructureAssets {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server: &AssetServer = world.resource();
+        StructureAssets {
+            spawn_sound: KingdomHandle {
+                elven: asset_server.load("sounds/elf_spawn.wav"),
+                monster: asset_server.load("sounds/monster_spawn.wav"),
+            },
+        }
+    }
+}
+
+pub struct NexusSpawnEvent {
+    pub blueprint: &'static SubjectBlueprint,
+    pub lane: Lane,
+    pub kingdom: Kingdom,
+}
+
+impl NexusSpawnEvent {
+    pub fn new(blueprint: &'static SubjectBlueprint, lane: Lane, kingdom: Kingdom) -> Self {
+        Self {
+            blueprint,
+            lane,
+            kingdom,
+        }
+    }
+}
+
+pub struct BuildEvent {
+    pub blueprint: &'static StructureBlueprint,
+    pub kingdom: Kingdom,
+}
+
+impl BuildEvent {
+    pub fn new(blueprint: &'static StructureBlueprint, kingdom: Kingdom) -> Self {
+        Self { blueprint, kingdom }
+    }
+}
+
+#[derive(Component)]
+pub struct Structure;
+
+#[derive(Component)]
+pub struct Nexus;
+
+#[derive(Component)]
+struct Tower;
+
+#[derive(Component)]
+struct Wall {
+    gate: bool,
+}
+
+impl Wall {
+    fn new(gate: bool) -> Self {
+        Self { gate }
+    }
+
+    fn blocks(&self, wall_kingdom: Kingdom, subject_kingdom: Kingdom) -> bool {
+        wall_kingdom != subject_kingdom
+    }
+
+    // friendlies walk through gates but have to climb over plain walls
+    fn slows(&self, wall_kingdom: Kingdom, subject_kingdom: Kingdom) -> bool {
+        !self.gate && wall_kingdom == subject_kingdom
+    }
+}
+
+#[derive(Component, Deref)]
+struct Slot(usize);
+
+fn spawn_nexuses(mut commands: Commands) {
+    let (nexus_size, nexus_y) = span_all_lanes(Vec2::new(2.0, 3.0));
+    commands.spawn((
+        Name::new("Elven nexus"),
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgba(0.2, 0.1, 0.1, 0.5),
+                custom_size: Some(nexus_size),
+                ..default()
+            },
+            transform: Transform::from_xyz(-WORLD_EXTENSION + 5.0, nexus_y, 0.0),
+            ..default()
+        },
+        RigidBody::Fixed,
+        ColliderBundle::new(
+            Collider::cuboid(nexus_size.x / 2.0, nexus_size.y / 2.0),
+            KingdomGroups::new(Kingdom::Elven),
+        ),
+        Kingdom::Elven,
+        Health::new(50),
+        NEXUS_ARMOR,
+        Deflecting,
+        Regeneration(0.0),
+        NEXUS_INSPIRATION,
+        Structure,
+        Nexus,
+    ));
+    commands.spawn((
+        Name::new("Monster nexus"),
+        SpriteBundle {
+            sprite: Sprite {
+                color: Color::rgba(0.2, 0.1, 0.1, 0.5),
+                custom_size: Some(nexus_size),
+                ..default()
+            },
+            transform: Transform::from_xyz(WORLD_EXTENSION - 5.0, nexus_y, 0.0),
+            ..default()
+        },
+        RigidBody::Fixed,
+        ColliderBundle::new(
+            Collider::cuboid(nexus_size.x / 2.0, nexus_size.y / 2.0),
+            KingdomGroups::new(Kingdom::Monster),
+        ),
+        Kingdom::Monster,
+        Health::new(50),
+        NEXUS_ARMOR,
+        Deflecting,
+        Regeneration(0.0),
+        NEXUS_INSPIRATION,
+        Structure,
+        Nexus,
+    ));
+}
+
+fn despawn_structures(query: Query<Entity, With<Structure>>, mut commands: Commands) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn spawn_on_nexus_spawn_event(
+    mut nexus_spawn_events: EventReader<NexusSpawnEvent>,
+    mut spawn_events: EventWriter<SpawnEvent>,
+    query: Query<(&Transform, &Kingdom), With<Nexus>>,
+    structure_assets: Res<StructureAssets>,
+    audio: Res<Audio>,
+) {
+    for nexus_spawn_event in nexus_spawn_events.iter() {
+        for (transform, kingdom) in &query {
+            if *kingdom != nexus_spawn_event.kingdom {
+                continue;
+            }
+
+            let lane = nexus_spawn_event.lane;
+            let position = Vec3::new(transform.translation.x, lane.ground(), lane.depth());
+
+            spawn_events.send(SpawnEvent::new(
+                nexus_spawn_event.blueprint,
+                position,
+                lane,
+                *kingdom,
+            ));
+
+            let sound = structure_assets.spawn_sound.get(*kingdom);
+            audio.play(sound).with_volume(0.1);
+        }
+    }
+}
+
+fn build_on_build_event(
+    mut events: EventReader<BuildEvent>,
+    nexus_query: Query<(&Transform, &Kingdom), With<Nexus>>,
+    slot_query: Query<(&Kingdom, &Slot)>,
+    mut coins: ResMut<Coins>,
+    structure_assets: Res<StructureAssets>,
+    audio: Res<Audio>,
+    mut commands: Commands,
+) {
+    let mut occupied_slots: Vec<_> = slot_query
+        .iter()
+        .map(|(kingdom, slot)| (*kingdom, **slot))
+        .collect();
+
+    for event in events.iter() {
+        let kingdom_coins = coins.get(event.kingdom) as u32;
+        if event.blueprint.value > kingdom_coins {
+            continue;
+        }
+        let Some(slot) =
+            (0..SLOT_OFFSETS.len()).find(|slot| !occupied_slots.contains(&(event.kingdom, *slot)))
+        else {
+            continue;
+        };
+        let Some(nexus_transform) = nexus_query
+            .iter()
+            .find_map(|(transform, kingdom)| (*kingdom == event.kingdom).then_some(transform))
+        else {
+            continue;
+        };
+
+        coins.set(
+            (kingdom_coins - event.blueprint.value) as f32,
+            event.kingdom,
+        );
+        occupied_slots.push((event.kingdom, slot));
+
+        let offset = match event.kingdom {
+            Kingdom::Elven => SLOT_OFFSETS[slot],
+            Kingdom::Monster => -SLOT_OFFSETS[slot],
+        };
+        let (size, y) = span_all_lanes(event.blueprint.size);
+        let position = Vec3::new(nexus_transform.translation.x + offset, y, 0.0);
+
+        let mut root_commands = commands.spawn((
+            Name::new(event.blueprint.name),
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgba(0.2, 0.1, 0.1, 0.5),
+                    custom_size: Some(size),
+                    ..default()
+                },
+                transform: Transform::from_translation(position),
+                ..default()
+            },
+            RigidBody::Fixed,
+            ColliderBundle::new(
+                Collider::cuboid(size.x / 2.0, size.y / 2.0),
+                KingdomGroups::new(event.kingdom),
+            ),
+            event.kingdom,
+            Health::new(event.blueprint.health),
+            event.blueprint.armor,
+            Reward(event.blueprint.value as f32),
+            Structure,
+            Slot(slot),
+        ));
+
+        match event.blueprint.kind {
+            StructureKind::Tower(weapon) => {
+                root_commands.insert(Tower);
+                insert_weapon(&mut root_commands, weapon, &WeaponModifiers::default());
+            }
+            StructureKind::Wall { gate } => {
+                root_commands.insert(Wall::new(gate));
+            }
+            StructureKind::Banner(inspiring) => {
+                root_commands.insert(inspiring);
+            }
+        }
+
+        let sound = structure_assets.spawn_sound.get(event.kingdom);
+        audio.play(sound).with_volume(0.1);
+    }
+}
+
+// towers cover every lane on both sides, so they shoot the nearest enemy in range
+fn aim_towers(
+    query: Query<(Entity, &Transform, &Kingdom, &Bow), With<Tower>>,
+    index: Res<SpatialIndex>,
+    mut events: EventWriter<ShotEvent>,
+) {
+    for (entity, transform, kingdom, bow) in &query {
+        if !bow.timer.finished() {
+            continue;
+        }
+        let position = transform.translation.x;
+        let Some(target) = index.nearest(kingdom.rival(), position, |entry| {
+            bow.anti_air || !entry.flying
+        }) else {
+            continue;
+        };
+        if (target.position - position).abs() < bow.range {
+            events.send(ShotEvent::new(entity).with_target(target.entity));
+        }
+    }
+}
+
+fn block_subjects(
+    subject_query: Query<
+        (Entity, &Kingdom, Option<&Blocked>, Option<&Climbing>),
+        (With<Subject>, Without<Flying>),
+    >,
+    wall_query: Query<(&Kingdom, &Wall)>,
+    context: Res<CollisionContext>,
+    mut commands: Commands,
+) {
+    for (subject_entity, subject_kingdom, blocked, climbing) in &subject_query {
+        let mut is_blocked = false;
+        let mut is_climbing = false;
+        for entity in intersections_with(subject_entity, &context) {
+            let Ok((wall_kingdom, wall)) = wall_query.get(entity) else {
+                continue;
+            };
+            is_blocked |= wall.blocks(*wall_kingdom, *subject_kingdom);
+            is_climbing |= wall.slows(*wall_kingdom, *subject_kingdom);
+        }
+
+        match (is_blocked, blocked.is_some()) {
+            (true, false) => {
+                commands.entity(subject_entity).insert(Blocked);
+            }
+            (false, true) => {
+                commands.entity(subject_entity).remove::<Blocked>();
+            }
+            _ => (),
+        }
+        match (is_climbing, climbing.is_some()) {
+            (true, false) => {
+                commands.entity(subject_entity).insert(Climbing);
+            }
+            (false, true) => {
+                commands.entity(subject_entity).remove::<Climbing>();
+            }
+            _ => (),
+        }
+    }
+}
+
+fn despawn_dead_structures(
+    mut events: EventReader<DeathEvent>,
+    query: Query<(), (With<Structure>, Without<Nexus>)>,
+    mut commands: Commands,
+) {
+    for event in events.iter() {
+        if query.contains(event.entity) {
+            commands.entity(event.entity).despawn_recursive();
+        }
+    }
+}
+
+fn finish_game_on_destroyed_nexus(
+    query: Query<&Health, With<Nexus>>,
+    mut state: ResMut<State<AppState>>,
+) {
+    for health in &query {
+        if health.is_dead() {
+            state.set(AppState::Menu).unwrap();
+        }
+    }
+}
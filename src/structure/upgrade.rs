@@ -0,0 +1,231 @@
+use bevy::prelude::*;
+
+use crate::{
+    palette,
+    recruitment::Coins,
+    ui::UiAssets,
+    unit::{Armor, Regeneration},
+    AppState, Kingdom,
+};
+
+use super::{Nexus, NEXUS_ARMOR};
+
+const MAX_LEVEL: u32 = 3;
+const ARMOR_MULTIPLIER_PER_LEVEL: f32 = 0.8;
+const REGENERATION_PER_LEVEL: f32 = 0.2;
+pub const INCOME_BONUS_PER_LEVEL: f32 = 0.5;
+const LEVEL_TEXT_SCALE: f32 = 1.0 / 60.0;
+
+pub struct NexusUpgradePlugin;
+
+impl Plugin for NexusUpgradePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NexusUpgrades>()
+            .add_event::<UpgradeEvent>()
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(reset_upgrades))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(upgrade_on_upgrade_event)
+                    .with_system(apply_upgrades.after(upgrade_on_upgrade_event))
+                    .with_system(label_nexuses)
+                    .with_system(update_level_texts.after(label_nexuses)),
+            );
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum NexusUpgrade {
+    Armor,
+    Regeneration,
+    Income,
+}
+
+impl NexusUpgrade {
+    pub const ALL: [NexusUpgrade; 3] = [
+        NexusUpgrade::Armor,
+        NexusUpgrade::Regeneration,
+        NexusUpgrade::Income,
+    ];
+
+    fn base_cost(&self) -> u32 {
+        match self {
+            NexusUpgrade::Armor => 10,
+            NexusUpgrade::Regeneration => 8,
+            NexusUpgrade::Income => 12,
+        }
+    }
+
+    pub fn initial(&self) -> char {
+        match self {
+            NexusUpgrade::Armor => 'A',
+            NexusUpgrade::Regeneration => 'R',
+            NexusUpgrade::Income => 'I',
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct UpgradeLevels {
+    armor: u32,
+    regeneration: u32,
+    income: u32,
+}
+
+impl UpgradeLevels {
+    pub fn get(&self, upgrade: NexusUpgrade) -> u32 {
+        match upgrade {
+            NexusUpgrade::Armor => self.armor,
+            NexusUpgrade::Regeneration => self.regeneration,
+            NexusUpgrade::Income => self.income,
+        }
+    }
+
+    fn get_mut(&mut self, upgrade: NexusUpgrade) -> &mut u32 {
+        match upgrade {
+            NexusUpgrade::Armor => &mut self.armor,
+            NexusUpgrade::Regeneration => &mut self.regeneration,
+            NexusUpgrade::Income => &mut self.income,
+        }
+    }
+
+    pub fn cost(&self, upgrade: NexusUpgrade) -> Option<u32> {
+        let level = self.get(upgrade);
+        (level < MAX_LEVEL).then_some(upgrade.base_cost() * (level + 1))
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct NexusUpgrades {
+    elven: UpgradeLevels,
+    monster: UpgradeLevels,
+}
+
+impl NexusUpgrades {
+    pub fn get(&self, kingdom: Kingdom) -> &UpgradeLevels {
+        match kingdom {
+            Kingdom::Elven => &self.elven,
+            Kingdom::Monster => &self.monster,
+        }
+    }
+
+    fn get_mut(&mut self, kingdom: Kingdom) -> &mut UpgradeLevels {
+        match kingdom {
+            Kingdom::Elven => &mut self.elven,
+            Kingdom::Monster => &mut self.monster,
+        }
+    }
+}
+
+pub struct UpgradeEvent {
+    pub upgrade: NexusUpgrade,
+    pub kingdom: Kingdom,
+}
+
+impl UpgradeEvent {
+    pub fn new(upgrade: NexusUpgrade, kingdom: Kingdom) -> Self {
+        Self { upgrade, kingdom }
+    }
+}
+
+#[derive(Component)]
+struct LevelText;
+
+fn reset_upgrades(mut upgrades: ResMut<NexusUpgrades>) {
+    *upgrades = NexusUpgrades::default();
+}
+
+fn upgrade_on_upgrade_event(
+    mut events: EventReader<UpgradeEvent>,
+    mut upgrades: ResMut<NexusUpgrades>,
+    mut coins: ResMut<Coins>,
+) {
+    for event in events.iter() {
+        let levels = upgrades.get_mut(event.kingdom);
+        let Some(cost) = levels.cost(event.upgrade) else {
+            continue;
+        };
+        let kingdom_coins = coins.get(event.kingdom) as u32;
+        if cost > kingdom_coins {
+            continue;
+        }
+
+        coins.set((kingdom_coins - cost) as f32, event.kingdom);
+        *levels.get_mut(event.upgrade) += 1;
+    }
+}
+
+fn apply_upgrades(
+    mut query: Query<(&Kingdom, &mut Armor, &mut Regeneration), With<Nexus>>,
+    upgrades: Res<NexusUpgrades>,
+) {
+    if !upgrades.is_changed() {
+        return;
+    }
+
+    for (kingdom, mut armor, mut regeneration) in &mut query {
+        let levels = upgrades.get(*kingdom);
+
+        let multiplier = ARMOR_MULTIPLIER_PER_LEVEL.powi(levels.get(NexusUpgrade::Armor) as i32);
+        *armor = Armor {
+            pierce: NEXUS_ARMOR.pierce * multiplier,
+            slash: NEXUS_ARMOR.slash * multiplier,
+            magic: NEXUS_ARMOR.magic * multiplier,
+            siege: NEXUS_ARMOR.siege * multiplier,
+        };
+
+        **regeneration = REGENERATION_PER_LEVEL * levels.get(NexusUpgrade::Regeneration) as f32;
+    }
+}
+
+fn label_nexuses(
+    query: Query<Entity, Added<Nexus>>,
+    assets: Res<UiAssets>,
+    mut commands: Commands,
+) {
+    for entity in &query {
+        let text = (
+            Text2dBundle {
+                text: Text::from_section(
+                    "",
+                    TextStyle {
+                        font: assets.font.clone(),
+                        font_size: 30.0,
+                        color: palette::DARK_BLACK,
+                    },
+                )
+                .with_alignment(TextAlignment::CENTER),
+                transform: Transform {
+                    translation: Vec3::new(0.0, 2.0, 1.0),
+                    scale: Vec3::splat(LEVEL_TEXT_SCALE),
+                    ..default()
+                },
+                ..default()
+            },
+            LevelText,
+        );
+        commands.entity(entity).with_children(|builder| {
+            builder.spawn(text);
+        });
+    }
+}
+
+fn update_level_texts(
+    nexus_query: Query<(&Kingdom, &Children), With<Nexus>>,
+    mut text_query: Query<&mut Text, With<LevelText>>,
+    upgrades: Res<NexusUpgrades>,
+) {
+    for (kingdom, children) in &nexus_query {
+        let levels = upgrades.get(*kingdom);
+        let label = NexusUpgrade::ALL
+            .iter()
+            .map(|upgrade| format!("{}{}", upgrade.initial(), levels.get(*upgrade)))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        for child in children {
+            if let Ok(mut text) = text_query.get_mut(*child) {
+                text.sections[0].value = label.clone();
+            }
+        }
+    }
+}
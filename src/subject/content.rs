@@ -2,8 +2,14 @@ use bevy::prelude::*;
 
 use crate::{
     animation::Animation,
+    research::Tech,
     subject::SubjectAnimations,
-    weapon::content::{WeaponBlueprint, ELVEN_BOW, ELVEN_FAST_BOW, ELVEN_SNIPER_BOW, GOBLIN_SWORD},
+    unit::Armor,
+    weapon::content::{
+        WeaponBlueprint, ELVEN_BOW, ELVEN_FAST_BOW, ELVEN_FROST_BOW, ELVEN_HERO_BOW,
+        ELVEN_SNIPER_BOW, ELVEN_STAFF, GOBLIN_BOMB, GOBLIN_CLUB, GOBLIN_DAGGER,
+        GOBLIN_DROPPED_BOMB, GOBLIN_SPEAR, GOBLIN_SWORD, GOBLIN_TOTEM, GOBLIN_WARLORD_AXE,
+    },
 };
 
 pub struct SubjectBlueprint {
@@ -11,22 +17,39 @@ pub struct SubjectBlueprint {
     pub value: u32,
     pub size: Vec2,
     pub health: u32,
+    pub armor: Armor,
     pub speed: f32,
+    pub knockback_resistance: f32,
+    pub flying: bool,
+    pub hero: bool,
     pub weapon: &'static WeaponBlueprint,
+    pub research: Option<Tech>,
     pub animations: SubjectAnimations,
 }
 
+const GOBLIN_ARMOR: Armor = Armor {
+    pierce: 0.5,
+    magic: 1.5,
+    ..Armor::NONE
+};
+
 pub const ELVEN_ARCHER: SubjectBlueprint = SubjectBlueprint {
     name: "Elven archer",
     value: 3,
     size: Vec2::new(1.0, 1.625),
     health: 1,
+    armor: Armor::NONE,
     speed: 1.5,
+    knockback_resistance: 0.0,
+    flying: false,
+    hero: false,
     weapon: &ELVEN_BOW,
+    research: None,
     animations: SubjectAnimations {
         idle: Animation::new(0, 2, 0.6),
         moving: Animation::new(7, 4, 0.3),
         shooting: Animation::new(14, 7, 0.1),
+        charging: None,
     },
 };
 
@@ -35,12 +58,18 @@ pub const ELVEN_FAST_ARCHER: SubjectBlueprint = SubjectBlueprint {
     value: 10,
     size: Vec2::new(1.0, 1.625),
     health: 1,
+    armor: Armor::NONE,
     speed: 3.5,
+    knockback_resistance: 0.0,
+    flying: false,
+    hero: false,
     weapon: &ELVEN_FAST_BOW,
+    research: Some(Tech::FastArchery),
     animations: SubjectAnimations {
         idle: Animation::new(0, 2, 0.3),
         moving: Animation::new(7, 4, 0.2),
         shooting: Animation::new(14, 7, 0.05),
+        charging: None,
     },
 };
 
@@ -49,12 +78,78 @@ pub const ELVEN_SNIPER_ARCHER: SubjectBlueprint = SubjectBlueprint {
     value: 3,
     size: Vec2::new(1.0, 1.625),
     health: 1,
+    armor: Armor::NONE,
     speed: 1.0,
+    knockback_resistance: 0.0,
+    flying: false,
+    hero: false,
     weapon: &ELVEN_SNIPER_BOW,
+    research: Some(Tech::SniperArchery),
     animations: SubjectAnimations {
         idle: Animation::new(0, 2, 0.6),
         moving: Animation::new(7, 4, 0.45),
         shooting: Animation::new(14, 7, 0.5),
+        charging: None,
+    },
+};
+
+pub const ELVEN_FROST_ARCHER: SubjectBlueprint = SubjectBlueprint {
+    name: "Elven frost archer",
+    value: 5,
+    size: Vec2::new(1.0, 1.625),
+    health: 1,
+    armor: Armor::NONE,
+    speed: 1.5,
+    knockback_resistance: 0.0,
+    flying: false,
+    hero: false,
+    weapon: &ELVEN_FROST_BOW,
+    research: None,
+    animations: SubjectAnimations {
+        idle: Animation::new(0, 2, 0.6),
+        moving: Animation::new(7, 4, 0.3),
+        shooting: Animation::new(14, 7, 0.1),
+        charging: None,
+    },
+};
+
+pub const ELVEN_HEALER: SubjectBlueprint = SubjectBlueprint {
+    name: "Elven healer",
+    value: 6,
+    size: Vec2::new(1.0, 1.625),
+    health: 2,
+    armor: Armor::NONE,
+    speed: 1.5,
+    knockback_resistance: 0.0,
+    flying: false,
+    hero: false,
+    weapon: &ELVEN_STAFF,
+    research: None,
+    animations: SubjectAnimations {
+        idle: Animation::new(0, 2, 0.6),
+        moving: Animation::new(7, 4, 0.3),
+        shooting: Animation::new(14, 7, 0.1),
+        charging: None,
+    },
+};
+
+pub const ELVEN_HERO: SubjectBlueprint = SubjectBlueprint {
+    name: "Elven ranger lord",
+    value: 30,
+    size: Vec2::new(1.0, 1.625),
+    health: 10,
+    armor: Armor::NONE,
+    speed: 1.5,
+    knockback_resistance: 0.5,
+    flying: false,
+    hero: true,
+    weapon: &ELVEN_HERO_BOW,
+    research: None,
+    animations: SubjectAnimations {
+        idle: Animation::new(0, 2, 0.6),
+        moving: Animation::new(7, 4, 0.3),
+        shooting: Animation::new(14, 7, 0.08),
+        charging: None,
     },
 };
 
@@ -63,11 +158,157 @@ pub const GOBLIN_WARRIOR: SubjectBlueprint = SubjectBlueprint {
     value: 1,
     size: Vec2::new(1.0, 1.375),
     health: 2,
+    armor: GOBLIN_ARMOR,
     speed: 2.5,
+    knockback_resistance: 0.0,
+    flying: false,
+    hero: false,
     weapon: &GOBLIN_SWORD,
+    research: None,
+    animations: SubjectAnimations {
+        idle: Animation::new(0, 2, 0.6),
+        moving: Animation::new(7, 4, 0.2),
+        shooting: Animation::new(0, 1, 1.0),
+        charging: None,
+    },
+};
+
+pub const GOBLIN_BOMB_THROWER: SubjectBlueprint = SubjectBlueprint {
+    name: "Goblin bomb thrower",
+    value: 4,
+    size: Vec2::new(1.0, 1.375),
+    health: 2,
+    armor: GOBLIN_ARMOR,
+    speed: 1.5,
+    knockback_resistance: 0.0,
+    flying: false,
+    hero: false,
+    weapon: &GOBLIN_BOMB,
+    research: None,
+    animations: SubjectAnimations {
+        idle: Animation::new(0, 2, 0.6),
+        moving: Animation::new(7, 4, 0.3),
+        shooting: Animation::new(14, 7, 0.15),
+        charging: None,
+    },
+};
+
+pub const GOBLIN_WOLF_RIDER: SubjectBlueprint = SubjectBlueprint {
+    name: "Goblin wolf rider",
+    value: 5,
+    size: Vec2::new(1.5, 1.375),
+    health: 3,
+    armor: GOBLIN_ARMOR,
+    speed: 4.0,
+    knockback_resistance: 0.3,
+    flying: false,
+    hero: false,
+    weapon: &GOBLIN_SPEAR,
+    research: None,
+    animations: SubjectAnimations {
+        idle: Animation::new(0, 2, 0.6),
+        moving: Animation::new(7, 4, 0.15),
+        shooting: Animation::new(0, 1, 1.0),
+        charging: Some(Animation::new(7, 4, 0.08)),
+    },
+};
+
+pub const GOBLIN_OGRE: SubjectBlueprint = SubjectBlueprint {
+    name: "Goblin ogre",
+    value: 8,
+    size: Vec2::new(1.5, 2.0),
+    health: 8,
+    armor: GOBLIN_ARMOR,
+    speed: 1.5,
+    knockback_resistance: 0.8,
+    flying: false,
+    hero: false,
+    weapon: &GOBLIN_CLUB,
+    research: None,
     animations: SubjectAnimations {
         idle: Animation::new(0, 2, 0.6),
+        moving: Animation::new(7, 4, 0.4),
+        shooting: Animation::new(0, 1, 1.0),
+        charging: None,
+    },
+};
+
+pub const GOBLIN_BAT_RIDER: SubjectBlueprint = SubjectBlueprint {
+    name: "Goblin bat rider",
+    value: 6,
+    size: Vec2::new(1.0, 1.0),
+    health: 2,
+    armor: GOBLIN_ARMOR,
+    speed: 2.0,
+    knockback_resistance: 0.0,
+    flying: true,
+    hero: false,
+    weapon: &GOBLIN_DROPPED_BOMB,
+    research: None,
+    animations: SubjectAnimations {
+        idle: Animation::new(7, 4, 0.2),
         moving: Animation::new(7, 4, 0.2),
+        shooting: Animation::new(14, 7, 0.1),
+        charging: None,
+    },
+};
+
+pub const GOBLIN_SHAMAN: SubjectBlueprint = SubjectBlueprint {
+    name: "Goblin shaman",
+    value: 6,
+    size: Vec2::new(1.0, 1.375),
+    health: 3,
+    armor: GOBLIN_ARMOR,
+    speed: 1.0,
+    knockback_resistance: 0.0,
+    flying: false,
+    hero: false,
+    weapon: &GOBLIN_TOTEM,
+    research: None,
+    animations: SubjectAnimations {
+        idle: Animation::new(0, 2, 0.6),
+        moving: Animation::new(7, 4, 0.4),
+        shooting: Animation::new(14, 7, 0.15),
+        charging: None,
+    },
+};
+
+pub const GOBLIN_MINION: SubjectBlueprint = SubjectBlueprint {
+    name: "Goblin minion",
+    value: 0,
+    size: Vec2::new(0.75, 1.0),
+    health: 1,
+    armor: GOBLIN_ARMOR,
+    speed: 3.0,
+    knockback_resistance: 0.0,
+    flying: false,
+    hero: false,
+    weapon: &GOBLIN_DAGGER,
+    research: None,
+    animations: SubjectAnimations {
+        idle: Animation::new(0, 2, 0.6),
+        moving: Animation::new(7, 4, 0.15),
+        shooting: Animation::new(0, 1, 1.0),
+        charging: None,
+    },
+};
+
+pub const GOBLIN_WARLORD: SubjectBlueprint = SubjectBlueprint {
+    name: "Goblin warlord",
+    value: 30,
+    size: Vec2::new(1.25, 1.75),
+    health: 20,
+    armor: GOBLIN_ARMOR,
+    speed: 2.0,
+    knockback_resistance: 0.9,
+    flying: false,
+    hero: true,
+    weapon: &GOBLIN_WARLORD_AXE,
+    research: None,
+    animations: SubjectAnimations {
+        idle: Animation::new(0, 2, 0.6),
+        moving: Animation::new(7, 4, 0.25),
         shooting: Animation::new(0, 1, 1.0),
+        charging: None,
     },
 };
@@ -0,0 +1,246 @@
+use bevy::prelude::*;
+
+use crate::{
+    lane::Lane,
+    mana::Mana,
+    structure::NexusSpawnEvent,
+    unit::{DeathEvent, ResolveDamage},
+    AppState, Kingdom,
+};
+
+use super::{content::SubjectBlueprint, morale::Inspiring, Boosts, SubjectModifiers};
+
+const MAX_LEVEL: u32 = 5;
+const EXPERIENCE_PER_LEVEL: u32 = 3;
+const HEALTH_BONUS_PER_LEVEL: u32 = 3;
+const DAMAGE_BONUS_PER_LEVEL: u32 = 1;
+const BASE_RESPAWN_SECONDS: f32 = 10.0;
+const RESPAWN_SECONDS_PER_LEVEL: f32 = 3.0;
+pub const REVIVE_MANA_COST: u32 = 30;
+const HERO_INSPIRATION: Inspiring = Inspiring {
+    radius: 6.0,
+    morale_per_second: 15.0,
+};
+
+pub struct HeroPlugin;
+
+impl Plugin for HeroPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Heroes>()
+            .add_event::<ReviveEvent>()
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(reset_heroes))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(track_spawned_heroes)
+                    .with_system(gain_experience.after(ResolveDamage))
+                    .with_system(start_respawn_timers.after(ResolveDamage))
+                    .with_system(revive_on_revive_event)
+                    .with_system(respawn_heroes.after(revive_on_revive_event)),
+            );
+    }
+}
+
+#[derive(Component)]
+pub struct Hero;
+
+#[derive(Default)]
+pub struct HeroState {
+    blueprint: Option<&'static SubjectBlueprint>,
+    lane: Lane,
+    entity: Option<Entity>,
+    level: u32,
+    experience: u32,
+    respawn_timer: Option<Timer>,
+}
+
+impl HeroState {
+    pub fn blueprint(&self) -> Option<&'static SubjectBlueprint> {
+        self.blueprint
+    }
+
+    pub fn level(&self) -> u32 {
+        self.level
+    }
+
+    pub fn experience(&self) -> u32 {
+        self.experience
+    }
+
+    pub fn next_level_experience(&self) -> Option<u32> {
+        (self.level < MAX_LEVEL).then_some(EXPERIENCE_PER_LEVEL * self.level)
+    }
+
+    pub fn respawn_seconds(&self) -> Option<f32> {
+        self.respawn_timer.as_ref().map(Timer::remaining_secs)
+    }
+
+    fn gain_experience(&mut self) -> bool {
+        let Some(next_level_experience) = self.next_level_experience() else {
+            return false;
+        };
+
+        self.experience += 1;
+        if self.experience < next_level_experience {
+            return false;
+        }
+        self.experience -= next_level_experience;
+        self.level += 1;
+        true
+    }
+
+    fn modifiers(&self) -> SubjectModifiers {
+        let bonus_levels = self.level.saturating_sub(1);
+        let mut modifiers = SubjectModifiers::default();
+        modifiers.weapon.damage_bonus += DAMAGE_BONUS_PER_LEVEL * bonus_levels;
+        modifiers.health_bonus += HEALTH_BONUS_PER_LEVEL * bonus_levels;
+        modifiers
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct Heroes {
+    elven: HeroState,
+    monster: HeroState,
+}
+
+impl Heroes {
+    pub fn get(&self, kingdom: Kingdom) -> &HeroState {
+        match kingdom {
+            Kingdom::Elven => &self.elven,
+            Kingdom::Monster => &self.monster,
+        }
+    }
+
+    fn get_mut(&mut self, kingdom: Kingdom) -> &mut HeroState {
+        match kingdom {
+            Kingdom::Elven => &mut self.elven,
+            Kingdom::Monster => &mut self.monster,
+        }
+    }
+
+    pub fn recruit(
+        &mut self,
+        blueprint: &'static SubjectBlueprint,
+        lane: Lane,
+        kingdom: Kingdom,
+    ) -> bool {
+        let state = self.get_mut(kingdom);
+        if state.blueprint.is_some() {
+            return false;
+        }
+
+        *state = HeroState {
+            blueprint: Some(blueprint),
+            lane,
+            level: 1,
+            ..default()
+        };
+        true
+    }
+}
+
+pub struct ReviveEvent {
+    pub kingdom: Kingdom,
+}
+
+impl ReviveEvent {
+    pub fn new(kingdom: Kingdom) -> Self {
+        Self { kingdom }
+    }
+}
+
+fn reset_heroes(mut heroes: ResMut<Heroes>) {
+    *heroes = Heroes::default();
+}
+
+fn track_spawned_heroes(
+    query: Query<(Entity, &Kingdom), Added<Hero>>,
+    mut heroes: ResMut<Heroes>,
+    mut commands: Commands,
+) {
+    for (entity, kingdom) in &query {
+        let state = heroes.get_mut(*kingdom);
+        state.entity = Some(entity);
+        commands
+            .entity(entity)
+            .insert((Boosts(state.modifiers()), HERO_INSPIRATION));
+    }
+}
+
+fn gain_experience(
+    mut events: EventReader<DeathEvent>,
+    mut query: Query<&mut Boosts, With<Hero>>,
+    mut heroes: ResMut<Heroes>,
+) {
+    for event in events.iter() {
+        if event.killer.kingdom == event.kingdom {
+            continue;
+        }
+        let state = heroes.get_mut(event.killer.kingdom);
+        let Some(entity) = state
+            .entity
+            .filter(|entity| Some(*entity) == event.killer.entity)
+        else {
+            continue;
+        };
+
+        if state.gain_experience() {
+            if let Ok(mut boosts) = query.get_mut(entity) {
+                boosts.0 = state.modifiers();
+            }
+        }
+    }
+}
+
+fn start_respawn_timers(mut events: EventReader<DeathEvent>, mut heroes: ResMut<Heroes>) {
+    for event in events.iter() {
+        let state = heroes.get_mut(event.kingdom);
+        if state.entity != Some(event.entity) {
+            continue;
+        }
+
+        let seconds = BASE_RESPAWN_SECONDS + RESPAWN_SECONDS_PER_LEVEL * state.level as f32;
+        state.entity = None;
+        state.respawn_timer = Some(Timer::from_seconds(seconds, TimerMode::Once));
+    }
+}
+
+fn revive_on_revive_event(
+    mut events: EventReader<ReviveEvent>,
+    mut heroes: ResMut<Heroes>,
+    mut mana: ResMut<Mana>,
+) {
+    for event in events.iter() {
+        let Some(timer) = &mut heroes.get_mut(event.kingdom).respawn_timer else {
+            continue;
+        };
+        if timer.finished() || !mana.spend(REVIVE_MANA_COST, event.kingdom) {
+            continue;
+        }
+
+        let duration = timer.duration();
+        timer.set_elapsed(duration);
+    }
+}
+
+fn respawn_heroes(
+    mut heroes: ResMut<Heroes>,
+    mut events: EventWriter<NexusSpawnEvent>,
+    time: Res<Time>,
+) {
+    for kingdom in [Kingdom::Elven, Kingdom::Monster] {
+        if heroes.get(kingdom).respawn_timer.is_none() {
+            continue;
+        }
+        let state = heroes.get_mut(kingdom);
+        let (Some(blueprint), Some(timer)) = (state.blueprint, &mut state.respawn_timer) else {
+            continue;
+        };
+
+        timer.tick(time.delta());
+        if timer.finished() {
+            state.respawn_timer = None;
+            events.send(NexusSpawnEvent::new(blueprint, state.lane, kingdom));
+        }
+    }
+}
@@ -1,35 +1,57 @@
 pub mod content;
+pub mod hero;
+pub mod morale;
+pub mod stance;
 pub mod state;
+pub mod upgrade;
 
 use bevy::{ecs::system::SystemState, prelude::*, sprite::Anchor};
 use bevy_kira_audio::prelude::*;
-use bevy_rapier2d::prelude::*;
 
 use crate::{
     animation::{Animation, AnimationMode, AnimationPlayer},
-    collision::ColliderBundle,
-    recruitment::Coins,
-    unit::Health,
-    weapon::{content::WeaponKind, Bow, Sword},
+    collision::{Collider, ColliderBundle, KingdomGroups, RigidBody, Velocity},
+    lane::Lane,
+    research::Research,
+    status::StatusEffects,
+    structure::Nexus,
+    unit::{DeathEvent, Health, Knockback, ResolveDamage},
+    weapon::{insert_weapon, Lifetime, WeaponModifiers},
     AppState, Kingdom, KingdomHandle, PX_PER_METER,
 };
 
 use self::{
     content::SubjectBlueprint,
-    state::{MovingState, SubjectStatePlugin, UpdateSubjectState},
+    hero::{Hero, HeroPlugin},
+    morale::{Morale, MoralePlugin},
+    stance::{StancePlugin, Stances},
+    state::{ChargingState, MovingState, RoutingState, SubjectStatePlugin, UpdateSubjectState},
+    upgrade::{kingdom_modifiers, SubjectUpgradePlugin, SubjectUpgrades},
 };
 
+const FLYING_ALTITUDE: f32 = 4.0;
+const CHARGE_SPEED_MULTIPLIER: f32 = 1.5;
+const CLIMB_SPEED_MULTIPLIER: f32 = 0.4;
+const RALLY_POINT_TOLERANCE: f32 = 0.5;
+
 #[derive(SystemLabel)]
 pub struct SpawnSubjects;
 
 #[derive(SystemLabel)]
 pub struct DamageSubjects;
 
+#[derive(SystemLabel)]
+pub struct DespawnSubjects;
+
 pub struct SubjectPlugin;
 
 impl Plugin for SubjectPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugin(SubjectStatePlugin)
+            .add_plugin(SubjectUpgradePlugin)
+            .add_plugin(HeroPlugin)
+            .add_plugin(MoralePlugin)
+            .add_plugin(StancePlugin)
             .init_resource::<SubjectAssets>()
             .add_event::<SpawnEvent>()
             .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn_subjects))
@@ -37,7 +59,11 @@ impl Plugin for SubjectPlugin {
                 SystemSet::on_update(AppState::Game)
                     .with_system(spawn_on_spawn_event.label(SpawnSubjects))
                     .with_system(set_subject_velocities.after(UpdateSubjectState))
-                    .with_system(despawn_dead_subjects.after(DamageSubjects)),
+                    .with_system(
+                        despawn_dead_subjects
+                            .label(DespawnSubjects)
+                            .after(ResolveDamage),
+                    ),
             );
     }
 }
@@ -75,15 +101,40 @@ impl FromWorld for SubjectAssets {
 pub struct SpawnEvent {
     pub blueprint: &'static SubjectBlueprint,
     pub position: Vec3,
+    pub lane: Lane,
     pub kingdom: Kingdom,
+    pub lifetime_seconds: Option<f32>,
 }
 
 impl SpawnEvent {
-    pub fn new(blueprint: &'static SubjectBlueprint, position: Vec3, kingdom: Kingdom) -> Self {
+    pub fn new(
+        blueprint: &'static SubjectBlueprint,
+        position: Vec3,
+        lane: Lane,
+        kingdom: Kingdom,
+    ) -> Self {
         Self {
             blueprint,
             position,
+            lane,
             kingdom,
+            lifetime_seconds: None,
+        }
+    }
+
+    pub fn summoned(
+        blueprint: &'static SubjectBlueprint,
+        position: Vec3,
+        lane: Lane,
+        kingdom: Kingdom,
+        lifetime_seconds: f32,
+    ) -> Self {
+        Self {
+            blueprint,
+            position,
+            lane,
+            kingdom,
+            lifetime_seconds: Some(lifetime_seconds),
         }
     }
 }
@@ -91,26 +142,90 @@ impl SpawnEvent {
 #[derive(Component)]
 pub struct Subject;
 
+#[derive(Component, Deref)]
+pub struct Blueprint(&'static SubjectBlueprint);
+
 #[derive(Component, Deref)]
 pub struct Speed(pub f32);
 
+#[derive(Component)]
+pub struct Flying;
+
+#[derive(Component)]
+#[component(storage = "SparseSet")]
+pub struct Blocked;
+
+#[derive(Component)]
+#[component(storage = "SparseSet")]
+pub struct Climbing;
+
 #[derive(Component, Deref)]
 pub struct Reward(pub f32);
 
+#[derive(Clone, Copy)]
+pub struct SubjectModifiers {
+    pub weapon: WeaponModifiers,
+    pub speed_multiplier: f32,
+    pub health_bonus: u32,
+}
+
+impl Default for SubjectModifiers {
+    fn default() -> Self {
+        Self {
+            weapon: WeaponModifiers::default(),
+            speed_multiplier: 1.0,
+            health_bonus: 0,
+        }
+    }
+}
+
+impl SubjectModifiers {
+    pub fn stack(&self, other: &SubjectModifiers) -> SubjectModifiers {
+        SubjectModifiers {
+            weapon: WeaponModifiers {
+                damage_bonus: self.weapon.damage_bonus + other.weapon.damage_bonus,
+                arrow_damage_bonus: self.weapon.arrow_damage_bonus
+                    + other.weapon.arrow_damage_bonus,
+                recharge_multiplier: self.weapon.recharge_multiplier
+                    * other.weapon.recharge_multiplier,
+            },
+            speed_multiplier: self.speed_multiplier * other.speed_multiplier,
+            health_bonus: self.health_bonus + other.health_bonus,
+        }
+    }
+}
+
+#[derive(Component, Deref)]
+pub struct Boosts(pub SubjectModifiers);
+
+// research only applies to subjects spawned after it completes
+#[derive(Component, Deref)]
+pub struct Researched(pub SubjectModifiers);
+
 #[derive(Component, Clone)]
 pub struct SubjectAnimations {
     idle: Animation,
     moving: Animation,
     shooting: Animation,
+    charging: Option<Animation>,
 }
 
 fn spawn_on_spawn_event(
     mut events: EventReader<SpawnEvent>,
+    research: Res<Research>,
+    upgrades: Res<SubjectUpgrades>,
     assets: Res<SubjectAssets>,
     mut commands: Commands,
 ) {
     for event in events.iter() {
+        let researched = research.get(event.kingdom).modifiers();
+        let modifiers = kingdom_modifiers(event.kingdom, &researched, &upgrades);
         let animation = &event.blueprint.animations.moving;
+        let altitude = if event.blueprint.flying {
+            FLYING_ALTITUDE
+        } else {
+            0.0
+        };
 
         let sprite = SpriteSheetBundle {
             texture_atlas: assets.atlas.clone(),
@@ -132,33 +247,50 @@ fn spawn_on_spawn_event(
         let mut root_commands = commands.spawn((
             Name::new(event.blueprint.name),
             SpatialBundle::from_transform(Transform::from_translation(
-                event.position + Vec3::new(0.0, event.blueprint.size.y / 2.0, 0.0),
+                event.position + Vec3::new(0.0, altitude + event.blueprint.size.y / 2.0, 0.0),
             )),
             AnimationPlayer::new(sprite_entity, animation, AnimationMode::Repeating),
             RigidBody::KinematicVelocityBased,
-            ColliderBundle::new(Collider::cuboid(
-                event.blueprint.size.x / 2.0,
-                event.blueprint.size.y / 2.0,
-            )),
+            ColliderBundle::new(
+                Collider::cuboid(event.blueprint.size.x / 2.0, event.blueprint.size.y / 2.0),
+                KingdomGroups::new(event.kingdom),
+            ),
             Velocity::zero(),
             event.kingdom,
-            Subject,
-            Health::new(event.blueprint.health),
-            Speed(event.blueprint.speed),
-            Reward(event.blueprint.value as f32 / 4.0),
+            (
+                Subject,
+                Blueprint(event.blueprint),
+                Researched(researched),
+                event.lane,
+            ),
+            Health::new(event.blueprint.health + modifiers.health_bonus),
+            event.blueprint.armor,
+            Speed(event.blueprint.speed * modifiers.speed_multiplier),
+            (
+                StatusEffects::default(),
+                Knockback::new(event.blueprint.knockback_resistance),
+                Morale::default(),
+            ),
             event.blueprint.animations.clone(),
             MovingState,
         ));
 
-        match &event.blueprint.weapon.kind {
-            WeaponKind::Sword => root_commands.insert(Sword::new(event.blueprint.weapon.damage)),
-            WeaponKind::Bow(k) => root_commands.insert(Bow::new(
-                event.blueprint.weapon.damage,
-                k.range,
-                k.spread,
-                k.speed,
-                k.recharge_seconds,
-            )),
+        insert_weapon(
+            &mut root_commands,
+            event.blueprint.weapon,
+            &modifiers.weapon,
+        );
+
+        if event.blueprint.flying {
+            root_commands.insert(Flying);
+        }
+        if event.blueprint.hero {
+            root_commands.insert(Hero);
+        }
+
+        match event.lifetime_seconds {
+            Some(seconds) => root_commands.insert(Lifetime::new(seconds)),
+            None => root_commands.insert(Reward(event.blueprint.value as f32)),
         };
 
         root_commands.push_children(&[sprite_entity]);
@@ -172,35 +304,101 @@ fn despawn_subjects(query: Query<Entity, With<Subject>>, mut commands: Commands)
 }
 
 fn despawn_dead_subjects(
-    query: Query<(Entity, &Kingdom, &Health, &Reward), With<Subject>>,
-    mut coins: ResMut<Coins>,
+    mut events: EventReader<DeathEvent>,
+    query: Query<(), With<Subject>>,
     subject_assets: Res<SubjectAssets>,
     audio: Res<Audio>,
     mut commands: Commands,
 ) {
-    for (entity, kingdom, health, reward) in &query {
-        if health.is_dead() {
-            commands.entity(entity).despawn_recursive();
+    for event in events.iter() {
+        if !query.contains(event.entity) {
+            continue;
+        }
 
-            let rival_kingdom_coins = coins.get(kingdom.rival());
-            coins.set(rival_kingdom_coins + **reward, kingdom.rival());
+        commands.entity(event.entity).despawn_recursive();
 
-            let sound = subject_assets.death_sound.get(*kingdom);
-            audio.play(sound).with_volume(0.2);
-        }
+        let sound = subject_assets.death_sound.get(event.kingdom);
+        audio.play(sound).with_volume(0.2);
     }
 }
 
 fn set_subject_velocities(
-    mut query: Query<(&mut Velocity, &Kingdom, &Speed, Option<&MovingState>), With<Subject>>,
+    mut query: Query<
+        (
+            &mut Velocity,
+            &Transform,
+            &Kingdom,
+            &Speed,
+            Option<&MovingState>,
+            Option<&ChargingState>,
+            Option<&RoutingState>,
+            Option<&Blocked>,
+            Option<&Climbing>,
+            Option<&StatusEffects>,
+            Option<&Knockback>,
+        ),
+        With<Subject>,
+    >,
+    nexus_query: Query<(&Transform, &Kingdom), With<Nexus>>,
+    stances: Res<Stances>,
 ) {
-    for (mut velocity, kingdom, speed, moving_state) in &mut query {
-        velocity.linvel.x = match moving_state {
-            Some(_) => match kingdom {
-                Kingdom::Elven => **speed,
-                Kingdom::Monster => -**speed,
-            },
-            None => 0.0,
+    for (
+        mut velocity,
+        transform,
+        kingdom,
+        speed,
+        moving_state,
+        charging_state,
+        routing_state,
+        blocked,
+        climbing,
+        status_effects,
+        knockback,
+    ) in &mut query
+    {
+        if let Some(knockback) = knockback.filter(|knockback| knockback.is_active()) {
+            velocity.linvel.x = knockback.velocity();
+            continue;
+        }
+
+        let mut speed = **speed * status_effects.map_or(1.0, StatusEffects::speed_multiplier);
+        if charging_state.is_some() {
+            speed *= CHARGE_SPEED_MULTIPLIER;
+        }
+        if climbing.is_some() {
+            speed *= CLIMB_SPEED_MULTIPLIER;
+        }
+        let is_moving = moving_state.is_some() || charging_state.is_some();
+        let direction = match kingdom {
+            Kingdom::Elven => 1.0,
+            Kingdom::Monster => -1.0,
+        };
+        let rally_point = nexus_query
+            .iter()
+            .find(|(_, nexus_kingdom)| *nexus_kingdom == kingdom)
+            .and_then(|(nexus_transform, _)| {
+                stances
+                    .get(*kingdom)
+                    .rally_point(nexus_transform.translation.x)
+            });
+        let linvel_x = match (is_moving, routing_state, rally_point) {
+            (_, Some(_), _) => -direction * speed,
+            (true, None, Some(rally_point)) => {
+                let offset = rally_point - transform.translation.x;
+                if offset.abs() > RALLY_POINT_TOLERANCE {
+                    offset.signum() * speed
+                } else {
+                    0.0
+                }
+            }
+            (true, None, None) => direction * speed,
+            _ => 0.0,
+        };
+        // walls only stop subjects from pushing forward, not from falling back
+        velocity.linvel.x = if blocked.is_some() && linvel_x * direction > 0.0 {
+            0.0
+        } else {
+            linvel_x
         };
     }
 }
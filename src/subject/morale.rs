@@ -0,0 +1,140 @@
+use bevy::prelude::*;
+
+use crate::{
+    lane::{shares_lane, Lane},
+    spatial::SpatialIndex,
+    structure::Nexus,
+    unit::{Armor, DamageEvent, DeathEvent, ResolveDamage, Shield},
+    AppState, Kingdom,
+};
+
+use super::{DamageSubjects, Subject};
+
+const MAX_MORALE: f32 = 100.0;
+const ROUT_THRESHOLD: f32 = 20.0;
+const RALLY_THRESHOLD: f32 = 60.0;
+const MORALE_PER_SECOND: f32 = 2.0;
+const ALLY_DEATH_RADIUS: f32 = 5.0;
+const ALLY_DEATH_MORALE_LOSS: f32 = 20.0;
+const NEXUS_HIT_MORALE_LOSS: f32 = 2.0;
+const MAX_NEXUS_MORALE_LOSS_PER_SECOND: f32 = 5.0;
+
+pub struct MoralePlugin;
+
+impl Plugin for MoralePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(
+            SystemSet::on_update(AppState::Game)
+                .with_system(regenerate_morale)
+                .with_system(lose_morale_on_nexus_hit.after(DamageSubjects))
+                .with_system(lose_morale_on_ally_death.after(ResolveDamage)),
+        );
+    }
+}
+
+#[derive(Component)]
+pub struct Morale(f32);
+
+impl Default for Morale {
+    fn default() -> Self {
+        Self(MAX_MORALE)
+    }
+}
+
+impl Morale {
+    fn change(&mut self, amount: f32) {
+        self.0 = (self.0 + amount).clamp(0.0, MAX_MORALE);
+    }
+
+    pub fn is_broken(&self) -> bool {
+        self.0 < ROUT_THRESHOLD
+    }
+
+    pub fn is_restored(&self) -> bool {
+        self.0 >= RALLY_THRESHOLD
+    }
+}
+
+#[derive(Component, Clone, Copy)]
+pub struct Inspiring {
+    pub radius: f32,
+    pub morale_per_second: f32,
+}
+
+fn regenerate_morale(
+    mut subject_query: Query<&mut Morale, With<Subject>>,
+    inspiring_query: Query<(&Transform, &Kingdom, &Inspiring, Option<&Lane>)>,
+    index: Res<SpatialIndex>,
+    time: Res<Time>,
+) {
+    for mut morale in &mut subject_query {
+        morale.change(MORALE_PER_SECOND * time.delta_seconds());
+    }
+
+    for (transform, kingdom, inspiring, lane) in &inspiring_query {
+        for entry in index.within(*kingdom, transform.translation.x, inspiring.radius) {
+            if !shares_lane(lane, entry.lane.as_ref()) {
+                continue;
+            }
+            if let Ok(mut morale) = subject_query.get_mut(entry.entity) {
+                morale.change(inspiring.morale_per_second * time.delta_seconds());
+            }
+        }
+    }
+}
+
+fn lose_morale_on_nexus_hit(
+    mut events: EventReader<DamageEvent>,
+    nexus_query: Query<(&Kingdom, Option<&Armor>), (With<Nexus>, Without<Shield>)>,
+    mut subject_query: Query<(&Kingdom, &mut Morale), With<Subject>>,
+    time: Res<Time>,
+) {
+    let mut elven_damage = 0.0;
+    let mut monster_damage = 0.0;
+    for event in events.iter() {
+        let Ok((nexus_kingdom, armor)) = nexus_query.get(event.target) else {
+            continue;
+        };
+
+        let amount = event.amount * armor.map_or(1.0, |armor| armor.multiplier(event.kind));
+        match nexus_kingdom {
+            Kingdom::Elven => elven_damage += amount,
+            Kingdom::Monster => monster_damage += amount,
+        }
+    }
+
+    // capped per second so continuous melee hits don't depend on the frame rate
+    let max_loss = MAX_NEXUS_MORALE_LOSS_PER_SECOND * time.delta_seconds();
+    for (kingdom, mut morale) in &mut subject_query {
+        let damage = match kingdom {
+            Kingdom::Elven => elven_damage,
+            Kingdom::Monster => monster_damage,
+        };
+        if damage > 0.0 {
+            morale.change(-f32::min(NEXUS_HIT_MORALE_LOSS * damage, max_loss));
+        }
+    }
+}
+
+fn lose_morale_on_ally_death(
+    mut events: EventReader<DeathEvent>,
+    dead_query: Query<(&Transform, Option<&Lane>)>,
+    mut subject_query: Query<&mut Morale, With<Subject>>,
+    index: Res<SpatialIndex>,
+) {
+    for event in events.iter() {
+        let Ok((dead_transform, dead_lane)) = dead_query.get(event.entity) else {
+            continue;
+        };
+        let position = dead_transform.translation.x;
+
+        for entry in index.within(event.kingdom, position, ALLY_DEATH_RADIUS) {
+            if !shares_lane(dead_lane, entry.lane.as_ref()) {
+                continue;
+            }
+            if let Ok(mut morale) = subject_query.get_mut(entry.entity) {
+                morale.change(-ALLY_DEATH_MORALE_LOSS);
+            }
+        }
+    }
+}
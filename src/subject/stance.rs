@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+
+use crate::{AppState, Kingdom};
+
+pub struct StancePlugin;
+
+impl Plugin for StancePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Stances>()
+            .add_event::<StanceEvent>()
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(reset_stances))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game).with_system(set_stance_on_stance_event),
+            );
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum Stance {
+    #[default]
+    Advance,
+    Hold(f32),
+    Retreat,
+}
+
+impl Stance {
+    pub fn rally_point(&self, nexus_position: f32) -> Option<f32> {
+        match self {
+            Stance::Advance => None,
+            Stance::Hold(position) => Some(*position),
+            Stance::Retreat => Some(nexus_position),
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct Stances {
+    elven: Stance,
+    monster: Stance,
+}
+
+impl Stances {
+    pub fn get(&self, kingdom: Kingdom) -> Stance {
+        match kingdom {
+            Kingdom::Elven => self.elven,
+            Kingdom::Monster => self.monster,
+        }
+    }
+
+    fn get_mut(&mut self, kingdom: Kingdom) -> &mut Stance {
+        match kingdom {
+            Kingdom::Elven => &mut self.elven,
+            Kingdom::Monster => &mut self.monster,
+        }
+    }
+}
+
+pub struct StanceEvent {
+    pub stance: Stance,
+    pub kingdom: Kingdom,
+}
+
+impl StanceEvent {
+    pub fn new(stance: Stance, kingdom: Kingdom) -> Self {
+        Self { stance, kingdom }
+    }
+}
+
+fn reset_stances(mut stances: ResMut<Stances>) {
+    *stances = Stances::default();
+}
+
+fn set_stance_on_stance_event(mut events: EventReader<StanceEvent>, mut stances: ResMut<Stances>) {
+    for event in events.iter() {
+        *stances.get_mut(event.kingdom) = event.stance;
+    }
+}
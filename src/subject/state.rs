@@ -2,12 +2,21 @@ use bevy::prelude::*;
 
 use crate::{
     animation::{AnimationMode, AnimationPlayer},
-    subject::{Subject, SubjectAnimations},
-    unit::Frontlines,
-    weapon::{Bow, ShotEvent},
+    lane::{shares_lane, Lane},
+    spatial::SpatialIndex,
+    status::Stunned,
+    subject::{
+        morale::Morale,
+        stance::{Stance, Stances},
+        Blocked, Subject, SubjectAnimations,
+    },
+    unit::{Frontlines, Health},
+    weapon::{Bow, Charge, HealEvent, ShotEvent, Staff, Sword, Totem},
     Kingdom,
 };
 
+const SUPPORT_STANDOFF: f32 = 3.0;
+
 #[derive(SystemLabel)]
 pub struct UpdateSubjectState;
 
@@ -17,7 +26,16 @@ impl Plugin for SubjectStatePlugin {
     fn build(&self, app: &mut App) {
         app.add_system(check_moving_transitions.label(UpdateSubjectState))
             .add_system(check_recharging_transitions.label(UpdateSubjectState))
-            .add_system(check_shooting_transitions.label(UpdateSubjectState));
+            .add_system(check_shooting_transitions.label(UpdateSubjectState))
+            .add_system(check_blocked_transitions.label(UpdateSubjectState))
+            .add_system(check_attacking_transitions.label(UpdateSubjectState))
+            .add_system(check_charging_transitions.label(UpdateSubjectState))
+            .add_system(check_support_moving_transitions.label(UpdateSubjectState))
+            .add_system(check_support_recharging_transitions.label(UpdateSubjectState))
+            .add_system(check_healer_shooting_transitions.label(UpdateSubjectState))
+            .add_system(check_routing_transitions.label(UpdateSubjectState))
+            // runs last so that its state removal wins over other transitions in the same frame
+            .add_system(check_broken_morale_transitions.after(UpdateSubjectState));
     }
 }
 
@@ -51,6 +69,37 @@ impl ShootingState {
     }
 }
 
+#[derive(Component)]
+#[component(storage = "SparseSet")]
+pub struct AttackingState;
+
+impl AttackingState {
+    fn transition(player: &mut AnimationPlayer, animations: &SubjectAnimations) {
+        player.set(&animations.shooting, AnimationMode::Repeating);
+    }
+}
+
+#[derive(Component)]
+#[component(storage = "SparseSet")]
+pub struct ChargingState;
+
+impl ChargingState {
+    fn transition(player: &mut AnimationPlayer, animations: &SubjectAnimations) {
+        let animation = animations.charging.as_ref().unwrap_or(&animations.moving);
+        player.set(animation, AnimationMode::Repeating);
+    }
+}
+
+#[derive(Component)]
+#[component(storage = "SparseSet")]
+pub struct RoutingState;
+
+impl RoutingState {
+    fn transition(player: &mut AnimationPlayer, animations: &SubjectAnimations) {
+        player.set(&animations.moving, AnimationMode::Repeating);
+    }
+}
+
 fn check_moving_transitions(
     mut subject_query: Query<
         (
@@ -60,14 +109,18 @@ fn check_moving_transitions(
             &Kingdom,
             &SubjectAnimations,
             &Bow,
+            Option<&Lane>,
         ),
-        (With<Subject>, With<MovingState>),
+        (With<Subject>, Without<Stunned>, With<MovingState>),
     >,
     frontlines: Res<Frontlines>,
+    stances: Res<Stances>,
     mut commands: Commands,
 ) {
-    for (entity, transform, mut player, kingdom, animations, bow) in &mut subject_query {
-        if frontline_in_range(transform, kingdom, bow, &frontlines) {
+    for (entity, transform, mut player, kingdom, animations, bow, lane) in &mut subject_query {
+        if !is_retreating(kingdom, &stances)
+            && frontline_in_range(transform, kingdom, lane, bow, &frontlines)
+        {
             commands.entity(entity).remove::<MovingState>();
             if bow.timer.finished() {
                 ShootingState::transition(&mut player, animations);
@@ -89,14 +142,18 @@ fn check_recharging_transitions(
             &Kingdom,
             &SubjectAnimations,
             &Bow,
+            Option<&Lane>,
         ),
-        (With<Subject>, With<RechargingState>),
+        (With<Subject>, Without<Stunned>, With<RechargingState>),
     >,
     frontlines: Res<Frontlines>,
+    stances: Res<Stances>,
     mut commands: Commands,
 ) {
-    for (entity, transform, mut player, kingdom, animations, bow) in &mut subject_query {
-        if !frontline_in_range(transform, kingdom, bow, &frontlines) {
+    for (entity, transform, mut player, kingdom, animations, bow, lane) in &mut subject_query {
+        if is_retreating(kingdom, &stances)
+            || !frontline_in_range(transform, kingdom, lane, bow, &frontlines)
+        {
             commands.entity(entity).remove::<RechargingState>();
             MovingState::transition(&mut player, animations);
             commands.entity(entity).insert(MovingState);
@@ -117,15 +174,19 @@ fn check_shooting_transitions(
             &Kingdom,
             &SubjectAnimations,
             &Bow,
+            Option<&Lane>,
         ),
-        (With<Subject>, With<ShootingState>),
+        (With<Subject>, Without<Stunned>, With<ShootingState>),
     >,
     frontlines: Res<Frontlines>,
+    stances: Res<Stances>,
     mut events: EventWriter<ShotEvent>,
     mut commands: Commands,
 ) {
-    for (entity, transform, mut player, kingdom, animations, bow) in &mut subject_query {
-        if !frontline_in_range(transform, kingdom, bow, &frontlines) {
+    for (entity, transform, mut player, kingdom, animations, bow, lane) in &mut subject_query {
+        if is_retreating(kingdom, &stances)
+            || !frontline_in_range(transform, kingdom, lane, bow, &frontlines)
+        {
             commands.entity(entity).remove::<ShootingState>();
             MovingState::transition(&mut player, animations);
             commands.entity(entity).insert(MovingState);
@@ -138,14 +199,313 @@ fn check_shooting_transitions(
     }
 }
 
+fn check_blocked_transitions(
+    mut subject_query: Query<
+        (Entity, &mut AnimationPlayer, &Kingdom, &SubjectAnimations),
+        (
+            With<Subject>,
+            Without<Stunned>,
+            With<Sword>,
+            With<MovingState>,
+            With<Blocked>,
+        ),
+    >,
+    stances: Res<Stances>,
+    mut commands: Commands,
+) {
+    for (entity, mut player, kingdom, animations) in &mut subject_query {
+        if is_retreating(kingdom, &stances) {
+            continue;
+        }
+
+        commands.entity(entity).remove::<MovingState>();
+        AttackingState::transition(&mut player, animations);
+        commands.entity(entity).insert(AttackingState);
+    }
+}
+
+fn check_attacking_transitions(
+    mut subject_query: Query<
+        (
+            Entity,
+            &mut AnimationPlayer,
+            &Kingdom,
+            &SubjectAnimations,
+            Option<&Blocked>,
+        ),
+        (With<Subject>, Without<Stunned>, With<AttackingState>),
+    >,
+    stances: Res<Stances>,
+    mut commands: Commands,
+) {
+    for (entity, mut player, kingdom, animations, blocked) in &mut subject_query {
+        if blocked.is_some() && !is_retreating(kingdom, &stances) {
+            continue;
+        }
+
+        commands.entity(entity).remove::<AttackingState>();
+        MovingState::transition(&mut player, animations);
+        commands.entity(entity).insert(MovingState);
+    }
+}
+
+fn check_charging_transitions(
+    mut subject_query: Query<
+        (
+            Entity,
+            &Transform,
+            &mut AnimationPlayer,
+            &Kingdom,
+            &SubjectAnimations,
+            &mut Charge,
+            Option<&MovingState>,
+            Option<&ChargingState>,
+            Option<&Blocked>,
+        ),
+        (With<Subject>, Without<Stunned>),
+    >,
+    stances: Res<Stances>,
+    mut commands: Commands,
+) {
+    for (
+        entity,
+        transform,
+        mut player,
+        kingdom,
+        animations,
+        mut charge,
+        moving,
+        charging,
+        blocked,
+    ) in &mut subject_query
+    {
+        let is_retreating = is_retreating(kingdom, &stances);
+        match (moving, charging, blocked) {
+            (_, Some(_), _) if charge.is_spent || is_retreating => {
+                commands.entity(entity).remove::<ChargingState>();
+                MovingState::transition(&mut player, animations);
+                commands.entity(entity).insert(MovingState);
+                charge.reset();
+            }
+            (_, Some(_), _) => {}
+            (Some(_), None, None) if !is_retreating => {
+                charge.track(transform.translation.x);
+                if charge.is_ready() {
+                    commands.entity(entity).remove::<MovingState>();
+                    ChargingState::transition(&mut player, animations);
+                    commands.entity(entity).insert(ChargingState);
+                }
+            }
+            _ => charge.reset(),
+        }
+    }
+}
+
+fn check_support_moving_transitions(
+    mut subject_query: Query<
+        (
+            Entity,
+            &Transform,
+            &mut AnimationPlayer,
+            &Kingdom,
+            &SubjectAnimations,
+            Option<&Lane>,
+        ),
+        (
+            With<Subject>,
+            Without<Stunned>,
+            Or<(With<Staff>, With<Totem>)>,
+            With<MovingState>,
+        ),
+    >,
+    frontlines: Res<Frontlines>,
+    stances: Res<Stances>,
+    mut commands: Commands,
+) {
+    for (entity, transform, mut player, kingdom, animations, lane) in &mut subject_query {
+        if !is_retreating(kingdom, &stances)
+            && !behind_own_frontline(transform, kingdom, lane, &frontlines)
+        {
+            commands.entity(entity).remove::<MovingState>();
+            RechargingState::transition(&mut player, animations);
+            commands.entity(entity).insert(RechargingState);
+        }
+    }
+}
+
+fn check_support_recharging_transitions(
+    mut subject_query: Query<
+        (
+            Entity,
+            &Transform,
+            &mut AnimationPlayer,
+            &Kingdom,
+            &SubjectAnimations,
+            Option<&Staff>,
+            Option<&Lane>,
+        ),
+        (
+            With<Subject>,
+            Without<Stunned>,
+            Or<(With<Staff>, With<Totem>)>,
+            With<RechargingState>,
+        ),
+    >,
+    health_query: Query<&Health>,
+    index: Res<SpatialIndex>,
+    frontlines: Res<Frontlines>,
+    stances: Res<Stances>,
+    mut commands: Commands,
+) {
+    for (entity, transform, mut player, kingdom, animations, staff, lane) in &mut subject_query {
+        if is_retreating(kingdom, &stances)
+            || behind_own_frontline(transform, kingdom, lane, &frontlines)
+        {
+            commands.entity(entity).remove::<RechargingState>();
+            MovingState::transition(&mut player, animations);
+            commands.entity(entity).insert(MovingState);
+        } else if staff.is_some_and(|staff| {
+            staff.timer.finished()
+                && damaged_friendly_in_range(transform, kingdom, lane, staff, &index, &health_query)
+                    .is_some()
+        }) {
+            commands.entity(entity).remove::<RechargingState>();
+            ShootingState::transition(&mut player, animations);
+            commands.entity(entity).insert(ShootingState);
+        }
+    }
+}
+
+fn check_healer_shooting_transitions(
+    mut subject_query: Query<
+        (Entity, &mut AnimationPlayer, &SubjectAnimations),
+        (
+            With<Subject>,
+            Without<Stunned>,
+            With<Staff>,
+            With<ShootingState>,
+        ),
+    >,
+    mut events: EventWriter<HealEvent>,
+    mut commands: Commands,
+) {
+    for (entity, mut player, animations) in &mut subject_query {
+        if player.is_finished() {
+            commands.entity(entity).remove::<ShootingState>();
+            RechargingState::transition(&mut player, animations);
+            commands.entity(entity).insert(RechargingState);
+            events.send(HealEvent::new(entity));
+        }
+    }
+}
+
+fn check_broken_morale_transitions(
+    mut subject_query: Query<
+        (
+            Entity,
+            &mut AnimationPlayer,
+            &Kingdom,
+            &SubjectAnimations,
+            &Morale,
+        ),
+        (With<Subject>, Without<RoutingState>),
+    >,
+    mut sprite_query: Query<&mut TextureAtlasSprite>,
+    mut commands: Commands,
+) {
+    for (entity, mut player, kingdom, animations, morale) in &mut subject_query {
+        if !morale.is_broken() {
+            continue;
+        }
+
+        commands.entity(entity).remove::<(
+            MovingState,
+            RechargingState,
+            ShootingState,
+            AttackingState,
+            ChargingState,
+        )>();
+        RoutingState::transition(&mut player, animations);
+        commands.entity(entity).insert(RoutingState);
+        if let Ok(mut sprite) = sprite_query.get_mut(player.sprite_entity()) {
+            sprite.flip_x = matches!(kingdom, Kingdom::Elven);
+        }
+    }
+}
+
+fn check_routing_transitions(
+    mut subject_query: Query<
+        (
+            Entity,
+            &mut AnimationPlayer,
+            &Kingdom,
+            &SubjectAnimations,
+            &Morale,
+        ),
+        (With<Subject>, With<RoutingState>),
+    >,
+    mut sprite_query: Query<&mut TextureAtlasSprite>,
+    mut commands: Commands,
+) {
+    for (entity, mut player, kingdom, animations, morale) in &mut subject_query {
+        if !morale.is_restored() {
+            continue;
+        }
+
+        commands.entity(entity).remove::<RoutingState>();
+        MovingState::transition(&mut player, animations);
+        commands.entity(entity).insert(MovingState);
+        if let Ok(mut sprite) = sprite_query.get_mut(player.sprite_entity()) {
+            sprite.flip_x = matches!(kingdom, Kingdom::Monster);
+        }
+    }
+}
+
 fn frontline_in_range(
     transform: &Transform,
     kingdom: &Kingdom,
+    lane: Option<&Lane>,
     bow: &Bow,
     frontlines: &Frontlines,
 ) -> bool {
+    let frontline = frontlines.target(*kingdom, lane, bow.anti_air);
     match kingdom {
-        Kingdom::Elven => (frontlines.monster.position - transform.translation.x) < bow.range,
-        Kingdom::Monster => (transform.translation.x - frontlines.elven.position) < bow.range,
+        Kingdom::Elven => (frontline.position - transform.translation.x) < bow.range,
+        Kingdom::Monster => (transform.translation.x - frontline.position) < bow.range,
     }
 }
+
+// retreating subjects disengage and don't pick new fights on their way back
+fn is_retreating(kingdom: &Kingdom, stances: &Stances) -> bool {
+    stances.get(*kingdom) == Stance::Retreat
+}
+
+fn behind_own_frontline(
+    transform: &Transform,
+    kingdom: &Kingdom,
+    lane: Option<&Lane>,
+    frontlines: &Frontlines,
+) -> bool {
+    let frontline = frontlines.own(*kingdom, lane);
+    match kingdom {
+        Kingdom::Elven => frontline.position - transform.translation.x > SUPPORT_STANDOFF,
+        Kingdom::Monster => transform.translation.x - frontline.position > SUPPORT_STANDOFF,
+    }
+}
+
+pub fn damaged_friendly_in_range(
+    transform: &Transform,
+    kingdom: &Kingdom,
+    lane: Option<&Lane>,
+    staff: &Staff,
+    index: &SpatialIndex,
+    health_query: &Query<&Health>,
+) -> Option<Entity> {
+    index
+        .within(*kingdom, transform.translation.x, staff.range)
+        .filter(|entry| shares_lane(lane, entry.lane.as_ref()))
+        .filter_map(|entry| Some((entry.entity, health_query.get(entry.entity).ok()?)))
+        .filter(|(_, health)| health.is_damaged())
+        .min_by(|(_, a), (_, b)| a.fraction().total_cmp(&b.fraction()))
+        .map(|(entity, _)| entity)
+}
@@ -0,0 +1,207 @@
+use bevy::prelude::*;
+
+use crate::{
+    recruitment::Coins,
+    unit::Health,
+    weapon::{Bow, Sword},
+    AppState, Kingdom,
+};
+
+use super::{Blueprint, Boosts, Researched, Speed, Subject, SubjectModifiers};
+
+const MAX_LEVEL: u32 = 3;
+const SPEED_BONUS_PER_LEVEL: f32 = 0.15;
+
+pub struct SubjectUpgradePlugin;
+
+impl Plugin for SubjectUpgradePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SubjectUpgrades>()
+            .add_event::<SubjectUpgradeEvent>()
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(reset_upgrades))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(upgrade_on_subject_upgrade_event)
+                    .with_system(apply_modifiers.after(upgrade_on_subject_upgrade_event)),
+            );
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SubjectUpgrade {
+    SharperArrows,
+    SwiftBoots,
+    ThickHide,
+}
+
+impl SubjectUpgrade {
+    pub const ALL: [SubjectUpgrade; 3] = [
+        SubjectUpgrade::SharperArrows,
+        SubjectUpgrade::SwiftBoots,
+        SubjectUpgrade::ThickHide,
+    ];
+
+    fn base_cost(&self) -> u32 {
+        match self {
+            SubjectUpgrade::SharperArrows => 15,
+            SubjectUpgrade::SwiftBoots => 10,
+            SubjectUpgrade::ThickHide => 12,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SubjectUpgrade::SharperArrows => "Sharper Arrows",
+            SubjectUpgrade::SwiftBoots => "Swift Boots",
+            SubjectUpgrade::ThickHide => "Thick Hide",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SubjectUpgrade::SharperArrows => "Arrows",
+            SubjectUpgrade::SwiftBoots => "Boots",
+            SubjectUpgrade::ThickHide => "Hide",
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct SubjectUpgradeLevels {
+    sharper_arrows: u32,
+    swift_boots: u32,
+    thick_hide: u32,
+}
+
+impl SubjectUpgradeLevels {
+    pub fn get(&self, upgrade: SubjectUpgrade) -> u32 {
+        match upgrade {
+            SubjectUpgrade::SharperArrows => self.sharper_arrows,
+            SubjectUpgrade::SwiftBoots => self.swift_boots,
+            SubjectUpgrade::ThickHide => self.thick_hide,
+        }
+    }
+
+    fn get_mut(&mut self, upgrade: SubjectUpgrade) -> &mut u32 {
+        match upgrade {
+            SubjectUpgrade::SharperArrows => &mut self.sharper_arrows,
+            SubjectUpgrade::SwiftBoots => &mut self.swift_boots,
+            SubjectUpgrade::ThickHide => &mut self.thick_hide,
+        }
+    }
+
+    pub fn cost(&self, upgrade: SubjectUpgrade) -> Option<u32> {
+        let level = self.get(upgrade);
+        (level < MAX_LEVEL).then_some(upgrade.base_cost() * (level + 1))
+    }
+
+    fn modifiers(&self) -> SubjectModifiers {
+        let mut modifiers = SubjectModifiers::default();
+        modifiers.weapon.arrow_damage_bonus += self.sharper_arrows;
+        modifiers.speed_multiplier *= 1.0 + SPEED_BONUS_PER_LEVEL * self.swift_boots as f32;
+        modifiers.health_bonus += self.thick_hide;
+        modifiers
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct SubjectUpgrades {
+    elven: SubjectUpgradeLevels,
+    monster: SubjectUpgradeLevels,
+}
+
+impl SubjectUpgrades {
+    pub fn get(&self, kingdom: Kingdom) -> &SubjectUpgradeLevels {
+        match kingdom {
+            Kingdom::Elven => &self.elven,
+            Kingdom::Monster => &self.monster,
+        }
+    }
+
+    fn get_mut(&mut self, kingdom: Kingdom) -> &mut SubjectUpgradeLevels {
+        match kingdom {
+            Kingdom::Elven => &mut self.elven,
+            Kingdom::Monster => &mut self.monster,
+        }
+    }
+}
+
+pub struct SubjectUpgradeEvent {
+    pub upgrade: SubjectUpgrade,
+    pub kingdom: Kingdom,
+}
+
+impl SubjectUpgradeEvent {
+    pub fn new(upgrade: SubjectUpgrade, kingdom: Kingdom) -> Self {
+        Self { upgrade, kingdom }
+    }
+}
+
+pub fn kingdom_modifiers(
+    kingdom: Kingdom,
+    researched: &SubjectModifiers,
+    upgrades: &SubjectUpgrades,
+) -> SubjectModifiers {
+    researched.stack(&upgrades.get(kingdom).modifiers())
+}
+
+fn reset_upgrades(mut upgrades: ResMut<SubjectUpgrades>) {
+    *upgrades = SubjectUpgrades::default();
+}
+
+fn upgrade_on_subject_upgrade_event(
+    mut events: EventReader<SubjectUpgradeEvent>,
+    mut upgrades: ResMut<SubjectUpgrades>,
+    mut coins: ResMut<Coins>,
+) {
+    for event in events.iter() {
+        let levels = upgrades.get_mut(event.kingdom);
+        let Some(cost) = levels.cost(event.upgrade) else {
+            continue;
+        };
+        let kingdom_coins = coins.get(event.kingdom) as u32;
+        if cost > kingdom_coins {
+            continue;
+        }
+
+        coins.set((kingdom_coins - cost) as f32, event.kingdom);
+        *levels.get_mut(event.upgrade) += 1;
+    }
+}
+
+fn apply_modifiers(
+    mut query: Query<
+        (
+            &Blueprint,
+            &Kingdom,
+            &Researched,
+            &mut Speed,
+            &mut Health,
+            Option<&mut Bow>,
+            Option<&mut Sword>,
+            Option<&Boosts>,
+        ),
+        With<Subject>,
+    >,
+    boosted_query: Query<(), Changed<Boosts>>,
+    upgrades: Res<SubjectUpgrades>,
+) {
+    if !upgrades.is_changed() && boosted_query.is_empty() {
+        return;
+    }
+
+    for (blueprint, kingdom, researched, mut speed, mut health, bow, sword, boosts) in &mut query {
+        let mut modifiers = kingdom_modifiers(*kingdom, researched, &upgrades);
+        if let Some(boosts) = boosts {
+            modifiers = modifiers.stack(boosts);
+        }
+        speed.0 = blueprint.speed * modifiers.speed_multiplier;
+        health.set_max((blueprint.health + modifiers.health_bonus) as f32);
+        if let Some(mut bow) = bow {
+            bow.apply_modifiers(blueprint.weapon, &modifiers.weapon);
+        }
+        if let Some(mut sword) = sword {
+            sword.apply_modifiers(blueprint.weapon, &modifiers.weapon);
+        }
+    }
+}
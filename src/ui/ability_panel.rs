@@ -0,0 +1,156 @@
+use bevy::prelude::*;
+
+use crate::{
+    ability::{Abilities, Ability, CastEvent},
+    cursor::CursorPosition,
+    palette, AppState, Kingdom,
+};
+
+use super::{battlefield_click, panel_button, Targeting, UiAssets};
+
+const COOLDOWN_ALPHA: f32 = 0.4;
+
+pub struct AbilityPanelPlugin;
+
+impl Plugin for AbilityPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(AppState::Game).with_system(spawn))
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(update_ability_buttons)
+                    .with_system(cast_on_battlefield_click),
+            )
+            .add_system(cast_on_click);
+    }
+}
+
+#[derive(Component)]
+struct AbilityPanel;
+
+#[derive(Component)]
+struct AbilityButton {
+    ability: Ability,
+}
+
+impl AbilityButton {
+    fn new(ability: Ability) -> Self {
+        Self { ability }
+    }
+}
+
+fn spawn(assets: Res<UiAssets>, mut commands: Commands) {
+    let root = (
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                // below the hero panel, the bottom edge is left to the recruitment row
+                position: UiRect::new(Val::Auto, Val::Px(40.0), Val::Px(120.0), Val::Auto),
+                ..default()
+            },
+            ..default()
+        },
+        AbilityPanel,
+    );
+    let space = NodeBundle {
+        style: Style {
+            size: Size::new(Val::Px(10.0), Val::Px(10.0)),
+            ..default()
+        },
+        ..default()
+    };
+    commands.spawn(root).with_children(|builder| {
+        for (i, ability) in Ability::ALL.into_iter().enumerate() {
+            if i > 0 {
+                builder.spawn(space.clone());
+            }
+            panel_button(
+                builder,
+                AbilityButton::new(ability),
+                palette::LIGHT_PINK,
+                &assets,
+            );
+        }
+    });
+}
+
+fn despawn(
+    query: Query<Entity, With<AbilityPanel>>,
+    mut targeting: ResMut<Targeting>,
+    mut commands: Commands,
+) {
+    let entity = query.single();
+    commands.entity(entity).despawn_recursive();
+    *targeting = Targeting::None;
+}
+
+fn cast_on_click(
+    query: Query<(&Interaction, &AbilityButton), Changed<Interaction>>,
+    mut targeting: ResMut<Targeting>,
+    mut events: EventWriter<CastEvent>,
+) {
+    for (interaction, button) in &query {
+        if !matches!(interaction, Interaction::Clicked) {
+            continue;
+        }
+
+        if button.ability.is_targeted() {
+            *targeting = Targeting::Ability(button.ability);
+        } else {
+            events.send(CastEvent::new(button.ability, Kingdom::Elven));
+        }
+    }
+}
+
+fn cast_on_battlefield_click(
+    interaction_query: Query<&Interaction>,
+    mouse: Res<Input<MouseButton>>,
+    cursor: Res<CursorPosition>,
+    mut targeting: ResMut<Targeting>,
+    mut events: EventWriter<CastEvent>,
+) {
+    let Targeting::Ability(ability) = *targeting else {
+        return;
+    };
+    if mouse.just_pressed(MouseButton::Right) {
+        *targeting = Targeting::None;
+        return;
+    }
+    let Some(position) = battlefield_click(&interaction_query, &mouse, &cursor) else {
+        return;
+    };
+
+    events.send(CastEvent::targeted(ability, Kingdom::Elven, position));
+    *targeting = Targeting::None;
+}
+
+fn update_ability_buttons(
+    mut button_query: Query<(&AbilityButton, &mut BackgroundColor, &Children)>,
+    mut text_query: Query<&mut Text>,
+    abilities: Res<Abilities>,
+    targeting: Res<Targeting>,
+) {
+    let kingdom_abilities = abilities.get(Kingdom::Elven);
+    for (button, mut color, children) in &mut button_query {
+        let ability = button.ability;
+        let status = if *targeting == Targeting::Ability(ability) {
+            "Aim".to_string()
+        } else if let Some(seconds) = kingdom_abilities.remaining_seconds(ability) {
+            format!("{}s", seconds.ceil() as u32)
+        } else {
+            ability.cost().to_string()
+        };
+        let alpha = if kingdom_abilities.is_ready(ability) {
+            1.0
+        } else {
+            COOLDOWN_ALPHA
+        };
+        color.0.set_a(alpha);
+
+        for child in children {
+            if let Ok(mut text) = text_query.get_mut(*child) {
+                text.sections[0].value = format!("{}\n{}", ability.label(), status);
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use bevy::prelude::*;
+
+use crate::{
+    palette,
+    subject::upgrade::{SubjectUpgrade, SubjectUpgradeEvent, SubjectUpgrades},
+    AppState, Kingdom,
+};
+
+use super::{panel_button, UiAssets};
+
+pub struct ArmyPanelPlugin;
+
+impl Plugin for ArmyPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(AppState::Game).with_system(spawn))
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(update_cost_texts)
+                    .with_system(update_active_text),
+            )
+            .add_system(upgrade_on_click);
+    }
+}
+
+#[derive(Component)]
+struct ArmyPanel;
+
+#[derive(Component)]
+struct ActiveUpgradesText;
+
+#[derive(Component)]
+struct ArmyUpgradeButton {
+    upgrade: SubjectUpgrade,
+}
+
+impl ArmyUpgradeButton {
+    fn new(upgrade: SubjectUpgrade) -> Self {
+        Self { upgrade }
+    }
+}
+
+fn spawn(assets: Res<UiAssets>, mut commands: Commands) {
+    let root = (
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                // below the stance panel, the bottom edge is left to the recruitment row
+                position: UiRect::new(Val::Px(40.0), Val::Auto, Val::Px(150.0), Val::Auto),
+                ..default()
+            },
+            ..default()
+        },
+        ArmyPanel,
+    );
+    let space = NodeBundle {
+        style: Style {
+            size: Size::new(Val::Px(10.0), Val::Px(10.0)),
+            ..default()
+        },
+        ..default()
+    };
+    commands.spawn(root).with_children(|builder| {
+        for (i, upgrade) in SubjectUpgrade::ALL.into_iter().enumerate() {
+            if i > 0 {
+                builder.spawn(space.clone());
+            }
+            panel_button(
+                builder,
+                ArmyUpgradeButton::new(upgrade),
+                palette::LIGHT_ORANGE,
+                &assets,
+            );
+        }
+    });
+
+    let text_root = (
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: UiRect::new(Val::Px(40.0), Val::Auto, Val::Px(220.0), Val::Auto),
+                ..default()
+            },
+            ..default()
+        },
+        ArmyPanel,
+    );
+    let text = (
+        TextBundle {
+            text: Text::from_section(
+                "Uninitialized",
+                TextStyle {
+                    font: assets.font.clone(),
+                    font_size: 20.0,
+                    color: palette::DARK_BLACK,
+                },
+            ),
+            ..default()
+        },
+        ActiveUpgradesText,
+    );
+    commands.spawn(text_root).with_children(|builder| {
+        builder.spawn(text);
+    });
+}
+
+fn despawn(query: Query<Entity, With<ArmyPanel>>, mut commands: Commands) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn upgrade_on_click(
+    query: Query<(&Interaction, &ArmyUpgradeButton), Changed<Interaction>>,
+    mut events: EventWriter<SubjectUpgradeEvent>,
+) {
+    for (interaction, button) in &query {
+        if matches!(interaction, Interaction::Clicked) {
+            events.send(SubjectUpgradeEvent::new(button.upgrade, Kingdom::Elven));
+        }
+    }
+}
+
+fn update_cost_texts(
+    button_query: Query<(&ArmyUpgradeButton, &Children)>,
+    mut text_query: Query<&mut Text>,
+    upgrades: Res<SubjectUpgrades>,
+) {
+    let levels = upgrades.get(Kingdom::Elven);
+    for (button, children) in &button_query {
+        let cost = match levels.cost(button.upgrade) {
+            Some(cost) => cost.to_string(),
+            None => "Max".to_string(),
+        };
+        for child in children {
+            if let Ok(mut text) = text_query.get_mut(*child) {
+                text.sections[0].value = format!("{}\n{}", button.upgrade.label(), cost);
+            }
+        }
+    }
+}
+
+fn update_active_text(
+    mut query: Query<&mut Text, With<ActiveUpgradesText>>,
+    upgrades: Res<SubjectUpgrades>,
+) {
+    let levels = upgrades.get(Kingdom::Elven);
+    let active = SubjectUpgrade::ALL
+        .iter()
+        .filter(|upgrade| levels.get(**upgrade) > 0)
+        .map(|upgrade| format!("{} {}", upgrade.name(), levels.get(*upgrade)))
+        .collect::<Vec<_>>();
+
+    let mut text = query.single_mut();
+    text.sections[0].value = active.join(", ");
+}
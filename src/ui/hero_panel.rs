@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+
+use crate::{
+    palette,
+    subject::hero::{Heroes, ReviveEvent, REVIVE_MANA_COST},
+    ui::UiAssets,
+    AppState, Kingdom,
+};
+
+pub struct HeroPanelPlugin;
+
+impl Plugin for HeroPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(AppState::Game).with_system(spawn_hero_panel))
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn_hero_panel))
+            .add_system_set(SystemSet::on_update(AppState::Game).with_system(update_hero_text))
+            .add_system(revive_on_click);
+    }
+}
+
+#[derive(Component)]
+struct HeroPanel;
+
+#[derive(Component)]
+struct HeroText;
+
+fn spawn_hero_panel(assets: Res<UiAssets>, mut commands: Commands) {
+    let root = (
+        ButtonBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: UiRect::new(Val::Auto, Val::Px(40.0), Val::Px(80.0), Val::Auto),
+                ..default()
+            },
+            background_color: Color::NONE.into(),
+            ..default()
+        },
+        HeroPanel,
+    );
+    let text = (
+        TextBundle {
+            text: Text::from_section(
+                "Uninitialized",
+                TextStyle {
+                    font: assets.font.clone(),
+                    font_size: 20.0,
+                    color: palette::DARK_BLACK,
+                },
+            ),
+            ..default()
+        },
+        HeroText,
+    );
+    commands.spawn(root).with_children(|builder| {
+        builder.spawn(text);
+    });
+}
+
+fn despawn_hero_panel(query: Query<Entity, With<HeroPanel>>, mut commands: Commands) {
+    let entity = query.single();
+    commands.entity(entity).despawn_recursive();
+}
+
+fn revive_on_click(
+    query: Query<&Interaction, (Changed<Interaction>, With<HeroPanel>)>,
+    mut events: EventWriter<ReviveEvent>,
+) {
+    for interaction in &query {
+        if matches!(interaction, Interaction::Clicked) {
+            events.send(ReviveEvent::new(Kingdom::Elven));
+        }
+    }
+}
+
+fn update_hero_text(heroes: Res<Heroes>, mut query: Query<&mut Text, With<HeroText>>) {
+    let mut text = query.single_mut();
+    let state = heroes.get(Kingdom::Elven);
+    text.sections[0].value = match (state.blueprint(), state.respawn_seconds()) {
+        (None, _) => String::from("No hero"),
+        (Some(_), Some(seconds)) => format!(
+            "Hero respawns in {}s (revive: {} mana)",
+            seconds.ceil() as u32,
+            REVIVE_MANA_COST
+        ),
+        (Some(_), None) => match state.next_level_experience() {
+            Some(next) => format!(
+                "Hero level {} ({}/{} xp)",
+                state.level(),
+                state.experience(),
+                next
+            ),
+            None => format!("Hero level {} (max)", state.level()),
+        },
+    };
+}
@@ -0,0 +1,136 @@
+use bevy::prelude::*;
+
+use crate::{lane::Lane, palette, AppState};
+
+use super::UiAssets;
+
+const UNSELECTED_ALPHA: f32 = 0.4;
+
+pub struct LanePanelPlugin;
+
+impl Plugin for LanePanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SelectedLane>()
+            .add_system_set(SystemSet::on_enter(AppState::Game).with_system(spawn))
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game).with_system(highlight_selected_lane),
+            )
+            .add_system(select_lane_on_click);
+    }
+}
+
+// the lane that recruited subjects are sent down
+#[derive(Resource, Default, Deref)]
+pub struct SelectedLane(Lane);
+
+#[derive(Component)]
+struct LanePanel;
+
+#[derive(Component, Deref)]
+struct LaneButton(Lane);
+
+fn spawn(assets: Res<UiAssets>, mut commands: Commands) {
+    let root = (
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                // left of the recruitment panel, lanes stacked like on the battlefield
+                position: UiRect::new(Val::Px(40.0), Val::Auto, Val::Auto, Val::Px(40.0)),
+                flex_direction: FlexDirection::ColumnReverse,
+                ..default()
+            },
+            ..default()
+        },
+        LanePanel,
+    );
+    let space = NodeBundle {
+        style: Style {
+            size: Size::new(Val::Px(10.0), Val::Px(10.0)),
+            ..default()
+        },
+        ..default()
+    };
+    commands.spawn(root).with_children(|builder| {
+        for (i, lane) in Lane::ALL.into_iter().enumerate() {
+            if i > 0 {
+                builder.spawn(space.clone());
+            }
+            lane_button(builder, lane, &assets);
+        }
+    });
+}
+
+fn despawn(
+    query: Query<Entity, With<LanePanel>>,
+    mut selected: ResMut<SelectedLane>,
+    mut commands: Commands,
+) {
+    let entity = query.single();
+    commands.entity(entity).despawn_recursive();
+    *selected = SelectedLane::default();
+}
+
+fn select_lane_on_click(
+    query: Query<(&Interaction, &LaneButton), Changed<Interaction>>,
+    mut selected: ResMut<SelectedLane>,
+) {
+    for (interaction, button) in &query {
+        if matches!(interaction, Interaction::Clicked) {
+            selected.0 = **button;
+        }
+    }
+}
+
+fn highlight_selected_lane(
+    mut query: Query<(&LaneButton, &mut BackgroundColor)>,
+    selected: Res<SelectedLane>,
+) {
+    if !selected.is_changed() {
+        return;
+    }
+
+    for (button, mut color) in &mut query {
+        let alpha = if **button == **selected {
+            1.0
+        } else {
+            UNSELECTED_ALPHA
+        };
+        color.0.set_a(alpha);
+    }
+}
+
+fn lane_button(builder: &mut ChildBuilder, lane: Lane, assets: &UiAssets) {
+    let root = (
+        ButtonBundle {
+            style: Style {
+                size: Size::new(Val::Px(60.0), Val::Px(30.0)),
+                ..default()
+            },
+            background_color: palette::LIGHT_GREEN.into(),
+            ..default()
+        },
+        LaneButton(lane),
+    );
+    let text = TextBundle {
+        text: Text {
+            sections: vec![TextSection::new(
+                lane.label(),
+                TextStyle {
+                    font: assets.font.clone(),
+                    font_size: 16.0,
+                    color: palette::DARK_BLACK,
+                },
+            )],
+            alignment: TextAlignment::TOP_RIGHT,
+        },
+        style: Style {
+            size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+            ..default()
+        },
+        ..default()
+    };
+    builder.spawn(root).with_children(|builder| {
+        builder.spawn(text);
+    });
+}
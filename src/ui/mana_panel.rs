@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+
+use crate::{mana::Mana, palette, ui::UiAssets, AppState, Kingdom};
+
+pub struct ManaPanelPlugin;
+
+impl Plugin for ManaPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(AppState::Game).with_system(spawn_mana_panel))
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn_mana_panel))
+            .add_system_set(SystemSet::on_update(AppState::Game).with_system(update_mana_text));
+    }
+}
+
+#[derive(Component)]
+struct ManaPanel;
+
+#[derive(Component)]
+struct ManaText;
+
+fn spawn_mana_panel(assets: Res<UiAssets>, mut commands: Commands) {
+    let root = (
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: UiRect::new(Val::Auto, Val::Px(140.0), Val::Px(40.0), Val::Auto),
+                ..default()
+            },
+            ..default()
+        },
+        ManaPanel,
+    );
+    let text = (
+        TextBundle {
+            text: Text::from_section(
+                "Uninitialized",
+                TextStyle {
+                    font: assets.font.clone(),
+                    font_size: 30.0,
+                    color: palette::DARK_PURPLE,
+                },
+            ),
+            ..default()
+        },
+        ManaText,
+    );
+    commands.spawn(root).with_children(|builder| {
+        builder.spawn(text);
+    });
+}
+
+fn despawn_mana_panel(query: Query<Entity, With<ManaPanel>>, mut commands: Commands) {
+    let entity = query.single();
+    commands.entity(entity).despawn_recursive();
+}
+
+fn update_mana_text(mana: Res<Mana>, mut query: Query<&mut Text, With<ManaText>>) {
+    let mut text = query.single_mut();
+    text.sections[0].value = format!("{} mana", mana.get(Kingdom::Elven) as u32);
+}
@@ -1,28 +1,52 @@
+mod ability_panel;
+mod army_panel;
 mod coin_panel;
+mod hero_panel;
+mod lane_panel;
+mod mana_panel;
 mod recruitment_panel;
+mod research_panel;
+mod stance_panel;
 mod start_menu;
+mod upgrade_panel;
+mod wind_panel;
 
 use bevy::prelude::*;
 
+use crate::{ability::Ability, cursor::CursorPosition, palette};
+
 use self::{
-    coin_panel::CoinPanelPlugin, recruitment_panel::RecruitmentPanelPlugin,
-    start_menu::StartMenuPlugin,
+    ability_panel::AbilityPanelPlugin, army_panel::ArmyPanelPlugin, coin_panel::CoinPanelPlugin,
+    hero_panel::HeroPanelPlugin, lane_panel::LanePanelPlugin, mana_panel::ManaPanelPlugin,
+    recruitment_panel::RecruitmentPanelPlugin, research_panel::ResearchPanelPlugin,
+    stance_panel::StancePanelPlugin, start_menu::StartMenuPlugin,
+    upgrade_panel::UpgradePanelPlugin, wind_panel::WindPanelPlugin,
 };
 
 pub struct UiPlugin;
 
 impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugin(CoinPanelPlugin)
+        app.add_plugin(AbilityPanelPlugin)
+            .add_plugin(ArmyPanelPlugin)
+            .add_plugin(CoinPanelPlugin)
+            .add_plugin(HeroPanelPlugin)
+            .add_plugin(LanePanelPlugin)
+            .add_plugin(ManaPanelPlugin)
             .add_plugin(RecruitmentPanelPlugin)
+            .add_plugin(ResearchPanelPlugin)
+            .add_plugin(StancePanelPlugin)
             .add_plugin(StartMenuPlugin)
-            .init_resource::<UiAssets>();
+            .add_plugin(UpgradePanelPlugin)
+            .add_plugin(WindPanelPlugin)
+            .init_resource::<UiAssets>()
+            .init_resource::<Targeting>();
     }
 }
 
 #[derive(Resource)]
-struct UiAssets {
-    font: Handle<Font>,
+pub struct UiAssets {
+    pub font: Handle<Font>,
 }
 
 impl FromWorld for UiAssets {
@@ -33,3 +57,70 @@ impl FromWorld for UiAssets {
         }
     }
 }
+
+// what the next battlefield click is aimed at, arming one mode disarms the others
+#[derive(Resource, Default, Clone, Copy, PartialEq)]
+enum Targeting {
+    #[default]
+    None,
+    Ability(Ability),
+    HoldLine,
+}
+
+fn battlefield_click(
+    interaction_query: &Query<&Interaction>,
+    mouse: &Input<MouseButton>,
+    cursor: &CursorPosition,
+) -> Option<Vec2> {
+    if !mouse.just_pressed(MouseButton::Left) {
+        return None;
+    }
+    // clicks on the ui are handled by the buttons
+    if interaction_query
+        .iter()
+        .any(|interaction| !matches!(interaction, Interaction::None))
+    {
+        return None;
+    }
+    **cursor
+}
+
+fn panel_button(
+    builder: &mut ChildBuilder,
+    marker: impl Component,
+    color: Color,
+    assets: &UiAssets,
+) {
+    let root = (
+        ButtonBundle {
+            style: Style {
+                size: Size::new(Val::Px(60.0), Val::Px(60.0)),
+                ..default()
+            },
+            background_color: color.into(),
+            ..default()
+        },
+        marker,
+    );
+    let text = TextBundle {
+        text: Text {
+            sections: vec![TextSection::new(
+                "Uninitialized",
+                TextStyle {
+                    font: assets.font.clone(),
+                    font_size: 16.0,
+                    color: palette::DARK_BLACK,
+                },
+            )],
+            alignment: TextAlignment::TOP_RIGHT,
+        },
+        style: Style {
+            size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+            ..default()
+        },
+        ..default()
+    };
+    builder.spawn(root).with_children(|builder| {
+        builder.spawn(text);
+    });
+}
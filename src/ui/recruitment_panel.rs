@@ -3,11 +3,24 @@ use bevy::{ecs::system::EntityCommands, prelude::*};
 use crate::{
     palette,
     recruitment::RecruitmentEvent,
-    subject::content::{SubjectBlueprint, ELVEN_ARCHER, ELVEN_FAST_ARCHER, ELVEN_SNIPER_ARCHER},
+    research::Research,
+    structure::{
+        content::{StructureBlueprint, BANNER, GATE, TOWER, WALL},
+        BuildEvent,
+    },
+    subject::{
+        content::{
+            SubjectBlueprint, ELVEN_ARCHER, ELVEN_FAST_ARCHER, ELVEN_FROST_ARCHER, ELVEN_HEALER,
+            ELVEN_HERO, ELVEN_SNIPER_ARCHER,
+        },
+        hero::Heroes,
+    },
     AppState, Kingdom,
 };
 
-use super::UiAssets;
+use super::{lane_panel::SelectedLane, UiAssets};
+
+const LOCKED_ALPHA: f32 = 0.4;
 
 pub struct RecruitmentPanelPlugin;
 
@@ -15,7 +28,11 @@ impl Plugin for RecruitmentPanelPlugin {
     fn build(&self, app: &mut App) {
         app.add_system_set(SystemSet::on_enter(AppState::Game).with_system(spawn))
             .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn))
-            .add_system(recruit_on_click);
+            .add_system_set(
+                SystemSet::on_update(AppState::Game).with_system(dim_locked_recruitment_buttons),
+            )
+            .add_system(recruit_on_click)
+            .add_system(build_on_click);
     }
 }
 
@@ -33,6 +50,17 @@ impl RecruitmentButton {
     }
 }
 
+#[derive(Component)]
+struct BuildButton {
+    blueprint: &'static StructureBlueprint,
+}
+
+impl BuildButton {
+    fn new(blueprint: &'static StructureBlueprint) -> Self {
+        Self { blueprint }
+    }
+}
+
 fn spawn(assets: Res<UiAssets>, mut commands: Commands) {
     let root = (
         NodeBundle {
@@ -82,6 +110,40 @@ fn spawn(assets: Res<UiAssets>, mut commands: Commands) {
             palette::DARK_ORANGE,
             &assets,
         );
+        builder.spawn(space.clone());
+        recruitment_button(
+            builder.spawn_empty(),
+            &ELVEN_FROST_ARCHER,
+            palette::DARK_CYAN,
+            &assets,
+        );
+        builder.spawn(space.clone());
+        recruitment_button(
+            builder.spawn_empty(),
+            &ELVEN_HEALER,
+            palette::DARK_GREEN,
+            &assets,
+        );
+        builder.spawn(space.clone());
+        recruitment_button(
+            builder.spawn_empty(),
+            &ELVEN_HERO,
+            palette::DARK_PINK,
+            &assets,
+        );
+        builder.spawn(space.clone());
+        build_button(builder.spawn_empty(), &TOWER, palette::DARK_PURPLE, &assets);
+        builder.spawn(space.clone());
+        build_button(builder.spawn_empty(), &WALL, palette::DARK_WHITE, &assets);
+        builder.spawn(space.clone());
+        build_button(builder.spawn_empty(), &GATE, palette::LIGHT_PURPLE, &assets);
+        builder.spawn(space.clone());
+        build_button(
+            builder.spawn_empty(),
+            &BANNER,
+            palette::LIGHT_YELLOW,
+            &assets,
+        );
     });
 }
 
@@ -92,36 +154,83 @@ fn despawn(query: Query<Entity, With<RecruitmentPanel>>, mut commands: Commands)
 
 fn recruit_on_click(
     query: Query<(&Interaction, &RecruitmentButton), Changed<Interaction>>,
+    selected_lane: Res<SelectedLane>,
     mut events: EventWriter<RecruitmentEvent>,
 ) {
     for (interaction, spawn) in &query {
         if matches!(interaction, Interaction::Clicked) {
-            events.send(RecruitmentEvent::new(spawn.blueprint, Kingdom::Elven));
+            events.send(RecruitmentEvent::new(
+                spawn.blueprint,
+                **selected_lane,
+                Kingdom::Elven,
+            ));
+        }
+    }
+}
+
+fn build_on_click(
+    query: Query<(&Interaction, &BuildButton), Changed<Interaction>>,
+    mut events: EventWriter<BuildEvent>,
+) {
+    for (interaction, build) in &query {
+        if matches!(interaction, Interaction::Clicked) {
+            events.send(BuildEvent::new(build.blueprint, Kingdom::Elven));
         }
     }
 }
 
+fn dim_locked_recruitment_buttons(
+    mut query: Query<(&RecruitmentButton, &mut BackgroundColor)>,
+    research: Res<Research>,
+    heroes: Res<Heroes>,
+) {
+    if !research.is_changed() && !heroes.is_changed() {
+        return;
+    }
+
+    let kingdom_research = research.get(Kingdom::Elven);
+    let hero_recruited = heroes.get(Kingdom::Elven).blueprint().is_some();
+    for (button, mut color) in &mut query {
+        let available = kingdom_research.is_unlocked(button.blueprint)
+            && !(button.blueprint.hero && hero_recruited);
+        let alpha = if available { 1.0 } else { LOCKED_ALPHA };
+        color.0.set_a(alpha);
+    }
+}
+
 fn recruitment_button(
     mut commands: EntityCommands,
     blueprint: &'static SubjectBlueprint,
     color: Color,
     assets: &UiAssets,
 ) {
-    let root = (
-        ButtonBundle {
-            style: Style {
-                size: Size::new(Val::Px(80.0), Val::Px(80.0)),
-                ..default()
-            },
-            background_color: color.into(),
+    commands.insert(RecruitmentButton::new(blueprint));
+    cost_button(commands, blueprint.value, color, assets);
+}
+
+fn build_button(
+    mut commands: EntityCommands,
+    blueprint: &'static StructureBlueprint,
+    color: Color,
+    assets: &UiAssets,
+) {
+    commands.insert(BuildButton::new(blueprint));
+    cost_button(commands, blueprint.value, color, assets);
+}
+
+fn cost_button(mut commands: EntityCommands, value: u32, color: Color, assets: &UiAssets) {
+    let root = ButtonBundle {
+        style: Style {
+            size: Size::new(Val::Px(80.0), Val::Px(80.0)),
             ..default()
         },
-        RecruitmentButton::new(blueprint),
-    );
+        background_color: color.into(),
+        ..default()
+    };
     let text = TextBundle {
         text: Text {
             sections: vec![TextSection::new(
-                blueprint.value.to_string(),
+                value.to_string(),
                 TextStyle {
                     font: assets.font.clone(),
                     font_size: 20.0,
@@ -0,0 +1,128 @@
+use bevy::prelude::*;
+
+use crate::{
+    palette,
+    research::{Research, ResearchEvent, Tech},
+    AppState, Kingdom,
+};
+
+use super::{panel_button, UiAssets};
+
+const UNAVAILABLE_ALPHA: f32 = 0.4;
+
+pub struct ResearchPanelPlugin;
+
+impl Plugin for ResearchPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(AppState::Game).with_system(spawn))
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game).with_system(update_research_buttons),
+            )
+            .add_system(research_on_click);
+    }
+}
+
+#[derive(Component)]
+struct ResearchPanel;
+
+#[derive(Component)]
+struct ResearchButton {
+    tech: Tech,
+}
+
+impl ResearchButton {
+    fn new(tech: Tech) -> Self {
+        Self { tech }
+    }
+}
+
+fn spawn(assets: Res<UiAssets>, mut commands: Commands) {
+    let root = (
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                // centered in the sky, clear of the recruitment row
+                position: UiRect {
+                    top: Val::Px(40.0),
+                    ..default()
+                },
+                size: Size {
+                    width: Val::Percent(100.0),
+                    ..default()
+                },
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ..default()
+        },
+        ResearchPanel,
+    );
+    let space = NodeBundle {
+        style: Style {
+            size: Size::new(Val::Px(10.0), Val::Px(10.0)),
+            ..default()
+        },
+        ..default()
+    };
+    commands.spawn(root).with_children(|builder| {
+        for (i, tech) in Tech::ALL.into_iter().enumerate() {
+            if i > 0 {
+                builder.spawn(space.clone());
+            }
+            panel_button(
+                builder,
+                ResearchButton::new(tech),
+                palette::LIGHT_GREEN,
+                &assets,
+            );
+        }
+    });
+}
+
+fn despawn(query: Query<Entity, With<ResearchPanel>>, mut commands: Commands) {
+    let entity = query.single();
+    commands.entity(entity).despawn_recursive();
+}
+
+fn research_on_click(
+    query: Query<(&Interaction, &ResearchButton), Changed<Interaction>>,
+    mut events: EventWriter<ResearchEvent>,
+) {
+    for (interaction, button) in &query {
+        if matches!(interaction, Interaction::Clicked) {
+            events.send(ResearchEvent::new(button.tech, Kingdom::Elven));
+        }
+    }
+}
+
+fn update_research_buttons(
+    mut button_query: Query<(&ResearchButton, &mut BackgroundColor, &Children)>,
+    mut text_query: Query<&mut Text>,
+    research: Res<Research>,
+) {
+    let kingdom_research = research.get(Kingdom::Elven);
+    for (button, mut color, children) in &mut button_query {
+        let tech = button.tech;
+        let status = if kingdom_research.is_researched(tech) {
+            "Done".to_string()
+        } else if let Some(progress) = kingdom_research.progress(tech) {
+            format!("{:.0}%", progress * 100.0)
+        } else {
+            tech.cost().to_string()
+        };
+        let alpha =
+            if kingdom_research.is_available(tech) || kingdom_research.progress(tech).is_some() {
+                1.0
+            } else {
+                UNAVAILABLE_ALPHA
+            };
+        color.0.set_a(alpha);
+
+        for child in children {
+            if let Ok(mut text) = text_query.get_mut(*child) {
+                text.sections[0].value = format!("{}\n{}", tech.label(), status);
+            }
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use bevy::prelude::*;
+
+use crate::{
+    cursor::CursorPosition,
+    palette,
+    subject::stance::{Stance, StanceEvent, Stances},
+    AppState, Kingdom, SKY_HEIGHT,
+};
+
+use super::{battlefield_click, panel_button, Targeting, UiAssets};
+
+const INACTIVE_ALPHA: f32 = 0.4;
+
+pub struct StancePanelPlugin;
+
+impl Plugin for StancePanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(AppState::Game).with_system(spawn))
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn))
+            .add_system_set(
+                SystemSet::on_update(AppState::Game)
+                    .with_system(update_stance_buttons)
+                    .with_system(update_hold_line_marker)
+                    .with_system(hold_on_battlefield_click),
+            )
+            .add_system(set_stance_on_click);
+    }
+}
+
+#[derive(Component)]
+struct StancePanel;
+
+#[derive(Component)]
+struct HoldLineMarker;
+
+#[derive(Component, Clone, Copy)]
+enum StanceButton {
+    Advance,
+    Hold,
+    Retreat,
+}
+
+impl StanceButton {
+    const ALL: [StanceButton; 3] = [
+        StanceButton::Advance,
+        StanceButton::Hold,
+        StanceButton::Retreat,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            StanceButton::Advance => "Advance",
+            StanceButton::Hold => "Hold",
+            StanceButton::Retreat => "Retreat",
+        }
+    }
+
+    fn is_active(&self, stance: Stance) -> bool {
+        matches!(
+            (self, stance),
+            (StanceButton::Advance, Stance::Advance)
+                | (StanceButton::Hold, Stance::Hold(_))
+                | (StanceButton::Retreat, Stance::Retreat)
+        )
+    }
+}
+
+fn spawn(assets: Res<UiAssets>, mut commands: Commands) {
+    let root = (
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: UiRect::new(Val::Px(40.0), Val::Auto, Val::Px(80.0), Val::Auto),
+                ..default()
+            },
+            ..default()
+        },
+        StancePanel,
+    );
+    let space = NodeBundle {
+        style: Style {
+            size: Size::new(Val::Px(10.0), Val::Px(10.0)),
+            ..default()
+        },
+        ..default()
+    };
+    commands.spawn(root).with_children(|builder| {
+        for (i, button) in StanceButton::ALL.into_iter().enumerate() {
+            if i > 0 {
+                builder.spawn(space.clone());
+            }
+            panel_button(builder, button, palette::LIGHT_BLUE, &assets);
+        }
+    });
+
+    let mut marker_color = palette::LIGHT_BLUE;
+    marker_color.set_a(0.5);
+    commands.spawn((
+        Name::new("Hold line"),
+        SpriteBundle {
+            sprite: Sprite {
+                color: marker_color,
+                custom_size: Some(Vec2::new(0.1, SKY_HEIGHT)),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, SKY_HEIGHT / 2.0, 1.0),
+            visibility: Visibility::INVISIBLE,
+            ..default()
+        },
+        HoldLineMarker,
+    ));
+}
+
+fn despawn(
+    panel_query: Query<Entity, With<StancePanel>>,
+    marker_query: Query<Entity, With<HoldLineMarker>>,
+    mut targeting: ResMut<Targeting>,
+    mut commands: Commands,
+) {
+    for entity in panel_query.iter().chain(&marker_query) {
+        commands.entity(entity).despawn_recursive();
+    }
+    *targeting = Targeting::None;
+}
+
+fn set_stance_on_click(
+    query: Query<(&Interaction, &StanceButton), Changed<Interaction>>,
+    mut targeting: ResMut<Targeting>,
+    mut events: EventWriter<StanceEvent>,
+) {
+    for (interaction, button) in &query {
+        if !matches!(interaction, Interaction::Clicked) {
+            continue;
+        }
+
+        match button {
+            StanceButton::Advance => {
+                events.send(StanceEvent::new(Stance::Advance, Kingdom::Elven));
+            }
+            StanceButton::Hold => *targeting = Targeting::HoldLine,
+            StanceButton::Retreat => {
+                events.send(StanceEvent::new(Stance::Retreat, Kingdom::Elven));
+            }
+        }
+    }
+}
+
+fn hold_on_battlefield_click(
+    interaction_query: Query<&Interaction>,
+    mouse: Res<Input<MouseButton>>,
+    cursor: Res<CursorPosition>,
+    mut targeting: ResMut<Targeting>,
+    mut events: EventWriter<StanceEvent>,
+) {
+    if *targeting != Targeting::HoldLine {
+        return;
+    }
+    if mouse.just_pressed(MouseButton::Right) {
+        *targeting = Targeting::None;
+        return;
+    }
+    let Some(position) = battlefield_click(&interaction_query, &mouse, &cursor) else {
+        return;
+    };
+
+    events.send(StanceEvent::new(Stance::Hold(position.x), Kingdom::Elven));
+    *targeting = Targeting::None;
+}
+
+fn update_stance_buttons(
+    mut button_query: Query<(&StanceButton, &mut BackgroundColor, &Children)>,
+    mut text_query: Query<&mut Text>,
+    stances: Res<Stances>,
+    targeting: Res<Targeting>,
+) {
+    let stance = stances.get(Kingdom::Elven);
+    for (button, mut color, children) in &mut button_query {
+        let is_aiming = *targeting == Targeting::HoldLine && matches!(button, StanceButton::Hold);
+        let alpha = if button.is_active(stance) || is_aiming {
+            1.0
+        } else {
+            INACTIVE_ALPHA
+        };
+        color.0.set_a(alpha);
+
+        for child in children {
+            if let Ok(mut text) = text_query.get_mut(*child) {
+                text.sections[0].value = if is_aiming {
+                    format!("{}\nAim", button.label())
+                } else {
+                    button.label().to_string()
+                };
+            }
+        }
+    }
+}
+
+fn update_hold_line_marker(
+    mut query: Query<(&mut Transform, &mut Visibility), With<HoldLineMarker>>,
+    stances: Res<Stances>,
+) {
+    if !stances.is_changed() {
+        return;
+    }
+
+    for (mut transform, mut visibility) in &mut query {
+        match stances.get(Kingdom::Elven) {
+            Stance::Hold(position) => {
+                transform.translation.x = position;
+                visibility.is_visible = true;
+            }
+            _ => visibility.is_visible = false,
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+
+use crate::{
+    palette,
+    structure::upgrade::{NexusUpgrade, NexusUpgrades, UpgradeEvent},
+    AppState, Kingdom,
+};
+
+use super::{panel_button, UiAssets};
+
+pub struct UpgradePanelPlugin;
+
+impl Plugin for UpgradePanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(AppState::Game).with_system(spawn))
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn))
+            .add_system_set(SystemSet::on_update(AppState::Game).with_system(update_cost_texts))
+            .add_system(upgrade_on_click);
+    }
+}
+
+#[derive(Component)]
+struct UpgradePanel;
+
+#[derive(Component)]
+struct UpgradeButton {
+    upgrade: NexusUpgrade,
+}
+
+impl UpgradeButton {
+    fn new(upgrade: NexusUpgrade) -> Self {
+        Self { upgrade }
+    }
+}
+
+fn spawn(assets: Res<UiAssets>, mut commands: Commands) {
+    let root = (
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                // beside the army panel, the bottom edge is left to the recruitment row
+                position: UiRect::new(Val::Px(260.0), Val::Auto, Val::Px(150.0), Val::Auto),
+                ..default()
+            },
+            ..default()
+        },
+        UpgradePanel,
+    );
+    let space = NodeBundle {
+        style: Style {
+            size: Size::new(Val::Px(10.0), Val::Px(10.0)),
+            ..default()
+        },
+        ..default()
+    };
+    commands.spawn(root).with_children(|builder| {
+        for (i, upgrade) in NexusUpgrade::ALL.into_iter().enumerate() {
+            if i > 0 {
+                builder.spawn(space.clone());
+            }
+            panel_button(
+                builder,
+                UpgradeButton::new(upgrade),
+                palette::LIGHT_BLUE,
+                &assets,
+            );
+        }
+    });
+}
+
+fn despawn(query: Query<Entity, With<UpgradePanel>>, mut commands: Commands) {
+    let entity = query.single();
+    commands.entity(entity).despawn_recursive();
+}
+
+fn upgrade_on_click(
+    query: Query<(&Interaction, &UpgradeButton), Changed<Interaction>>,
+    mut events: EventWriter<UpgradeEvent>,
+) {
+    for (interaction, button) in &query {
+        if matches!(interaction, Interaction::Clicked) {
+            events.send(UpgradeEvent::new(button.upgrade, Kingdom::Elven));
+        }
+    }
+}
+
+fn update_cost_texts(
+    button_query: Query<(&UpgradeButton, &Children)>,
+    mut text_query: Query<&mut Text>,
+    upgrades: Res<NexusUpgrades>,
+) {
+    let levels = upgrades.get(Kingdom::Elven);
+    for (button, children) in &button_query {
+        let cost = match levels.cost(button.upgrade) {
+            Some(cost) => cost.to_string(),
+            None => "Max".to_string(),
+        };
+        for child in children {
+            if let Ok(mut text) = text_query.get_mut(*child) {
+                text.sections[0].value = format!("{} {}", button.upgrade.initial(), cost);
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+
+use crate::{palette, ui::UiAssets, wind::Wind, AppState};
+
+const CALM_THRESHOLD: f32 = 0.1;
+
+pub struct WindPanelPlugin;
+
+impl Plugin for WindPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(AppState::Game).with_system(spawn_wind_panel))
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn_wind_panel))
+            .add_system_set(SystemSet::on_update(AppState::Game).with_system(update_wind_text));
+    }
+}
+
+#[derive(Component)]
+struct WindPanel;
+
+#[derive(Component)]
+struct WindText;
+
+fn spawn_wind_panel(assets: Res<UiAssets>, mut commands: Commands) {
+    let root = (
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: UiRect::new(Val::Px(40.0), Val::Auto, Val::Px(40.0), Val::Auto),
+                ..default()
+            },
+            ..default()
+        },
+        WindPanel,
+    );
+    let text = (
+        TextBundle {
+            text: Text::from_section(
+                "Uninitialized",
+                TextStyle {
+                    font: assets.font.clone(),
+                    font_size: 30.0,
+                    color: palette::DARK_BLACK,
+                },
+            ),
+            ..default()
+        },
+        WindText,
+    );
+    commands.spawn(root).with_children(|builder| {
+        builder.spawn(text);
+    });
+}
+
+fn despawn_wind_panel(query: Query<Entity, With<WindPanel>>, mut commands: Commands) {
+    let entity = query.single();
+    commands.entity(entity).despawn_recursive();
+}
+
+fn update_wind_text(wind: Res<Wind>, mut query: Query<&mut Text, With<WindText>>) {
+    let mut text = query.single_mut();
+    let acceleration = wind.acceleration();
+    text.sections[0].value = if acceleration.abs() < CALM_THRESHOLD {
+        "Calm".to_string()
+    } else if acceleration > 0.0 {
+        format!("Wind >> {:.1}", acceleration)
+    } else {
+        format!("Wind << {:.1}", -acceleration)
+    };
+}
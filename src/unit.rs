@@ -1,33 +1,120 @@
 use bevy::prelude::*;
 
-use crate::Kingdom;
+use crate::{
+    lane::{shares_lane, Lane, LANE_COUNT},
+    spatial::SpatialIndex,
+    status::{StatusEffectBlueprint, StatusEffects},
+    subject::DamageSubjects,
+    Kingdom,
+};
+
+const KNOCKBACK_SECONDS: f32 = 0.25;
+
+#[derive(SystemLabel)]
+pub struct ResolveDamage;
 
 pub struct UnitPlugin;
 
 impl Plugin for UnitPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<Frontlines>()
-            .add_system(update_frontlines);
+            .add_event::<DamageEvent>()
+            .add_event::<DeathEvent>()
+            .add_system(update_frontlines)
+            .add_system(regenerate_health)
+            .add_system(tick_knockbacks)
+            .add_system(tick_shields)
+            .add_system(resolve_damage.label(ResolveDamage).after(DamageSubjects));
     }
 }
 
-#[derive(Resource)]
+#[derive(Resource, Default)]
 pub struct Frontlines {
-    pub elven: Frontline,
-    pub monster: Frontline,
+    lanes: [LaneFrontlines; LANE_COUNT],
+}
+
+impl Frontlines {
+    pub fn target(&self, kingdom: Kingdom, lane: Option<&Lane>, anti_air: bool) -> &Frontline {
+        match lane {
+            Some(lane) => self.lanes[lane.index()].target(kingdom, anti_air),
+            // laneless shooters aim at the closest threat of any lane
+            None => most_advanced(
+                kingdom.rival(),
+                self.lanes
+                    .iter()
+                    .map(|frontlines| frontlines.target(kingdom, anti_air)),
+            ),
+        }
+    }
+
+    pub fn own(&self, kingdom: Kingdom, lane: Option<&Lane>) -> &Frontline {
+        match lane {
+            Some(lane) => self.lanes[lane.index()].own(kingdom),
+            None => most_advanced(
+                kingdom,
+                self.lanes.iter().map(|frontlines| frontlines.own(kingdom)),
+            ),
+        }
+    }
+}
+
+fn most_advanced<'a>(
+    kingdom: Kingdom,
+    frontlines: impl Iterator<Item = &'a Frontline>,
+) -> &'a Frontline {
+    frontlines
+        .max_by(|a, b| match kingdom {
+            Kingdom::Elven => a.position.total_cmp(&b.position),
+            Kingdom::Monster => b.position.total_cmp(&a.position),
+        })
+        .unwrap()
+}
+
+struct LaneFrontlines {
+    elven: Frontline,
+    monster: Frontline,
+    elven_air: Frontline,
+    monster_air: Frontline,
+}
+
+impl LaneFrontlines {
+    fn get_mut(&mut self, kingdom: Kingdom, flying: bool) -> &mut Frontline {
+        match (kingdom, flying) {
+            (Kingdom::Elven, false) => &mut self.elven,
+            (Kingdom::Elven, true) => &mut self.elven_air,
+            (Kingdom::Monster, false) => &mut self.monster,
+            (Kingdom::Monster, true) => &mut self.monster_air,
+        }
+    }
+
+    fn own(&self, kingdom: Kingdom) -> &Frontline {
+        match kingdom {
+            Kingdom::Elven => &self.elven,
+            Kingdom::Monster => &self.monster,
+        }
+    }
+
+    fn target(&self, kingdom: Kingdom, anti_air: bool) -> &Frontline {
+        match kingdom {
+            Kingdom::Elven if anti_air && self.monster_air.position < self.monster.position => {
+                &self.monster_air
+            }
+            Kingdom::Elven => &self.monster,
+            Kingdom::Monster if anti_air && self.elven_air.position > self.elven.position => {
+                &self.elven_air
+            }
+            Kingdom::Monster => &self.elven,
+        }
+    }
 }
 
-impl Default for Frontlines {
+impl Default for LaneFrontlines {
     fn default() -> Self {
         Self {
-            elven: Frontline {
-                position: f32::NEG_INFINITY,
-                entity: None,
-            },
-            monster: Frontline {
-                position: f32::INFINITY,
-                entity: None,
-            },
+            elven: Frontline::empty(Kingdom::Elven),
+            monster: Frontline::empty(Kingdom::Monster),
+            elven_air: Frontline::empty(Kingdom::Elven),
+            monster_air: Frontline::empty(Kingdom::Monster),
         }
     }
 }
@@ -37,49 +124,298 @@ pub struct Frontline {
     pub entity: Option<Entity>,
 }
 
+impl Frontline {
+    fn empty(kingdom: Kingdom) -> Self {
+        let position = match kingdom {
+            Kingdom::Elven => f32::NEG_INFINITY,
+            Kingdom::Monster => f32::INFINITY,
+        };
+        Self {
+            position,
+            entity: None,
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct Health {
-    current: u32,
+    current: f32,
+    max: f32,
 }
 
 impl Health {
-    pub fn new(initial: u32) -> Self {
-        Self { current: initial }
+    pub fn new(max: u32) -> Self {
+        Self {
+            current: max as f32,
+            max: max as f32,
+        }
+    }
+
+    pub fn damage(&mut self, amount: f32) {
+        self.current = f32::max(self.current - amount, 0.0);
     }
 
-    pub fn damage(&mut self, amount: u32) {
-        self.current = self.current.saturating_sub(amount);
+    pub fn heal(&mut self, amount: f32) {
+        self.current = f32::min(self.current + amount, self.max);
+    }
+
+    pub fn set_max(&mut self, max: f32) {
+        self.current = f32::min(self.current + f32::max(max - self.max, 0.0), max);
+        self.max = max;
     }
 
     pub fn is_dead(&self) -> bool {
-        self.current == 0
+        self.current == 0.0
+    }
+
+    pub fn is_damaged(&self) -> bool {
+        !self.is_dead() && self.current < self.max
+    }
+
+    pub fn fraction(&self) -> f32 {
+        self.current / self.max
     }
 }
 
-fn update_frontlines(
-    query: Query<(Entity, &Transform, &Kingdom), With<Health>>,
-    mut frontlines: ResMut<Frontlines>,
-) {
-    frontlines.elven.position = f32::NEG_INFINITY;
-    frontlines.monster.position = f32::INFINITY;
+#[derive(Component)]
+pub struct Knockback {
+    resistance: f32,
+    velocity: f32,
+    timer: Timer,
+}
 
-    frontlines.elven.entity = None;
-    frontlines.monster.entity = None;
+impl Knockback {
+    pub fn new(resistance: f32) -> Self {
+        let mut timer = Timer::from_seconds(KNOCKBACK_SECONDS, TimerMode::Once);
+        timer.set_elapsed(timer.duration());
+        Self {
+            resistance,
+            velocity: 0.0,
+            timer,
+        }
+    }
 
-    for (entity, transform, kingdom) in &query {
-        match kingdom {
-            Kingdom::Elven => {
-                if transform.translation.x > frontlines.elven.position {
-                    frontlines.elven.position = transform.translation.x;
-                    frontlines.elven.entity = Some(entity);
+    fn push(&mut self, velocity: f32) {
+        if self.is_active() {
+            return;
+        }
+        self.velocity = velocity * (1.0 - self.resistance).max(0.0);
+        self.timer.reset();
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.timer.finished() && self.velocity != 0.0
+    }
+
+    pub fn velocity(&self) -> f32 {
+        self.velocity * self.timer.percent_left()
+    }
+}
+
+#[derive(Component, Deref, DerefMut)]
+pub struct Regeneration(pub f32);
+
+#[derive(Component)]
+#[component(storage = "SparseSet")]
+pub struct Shield {
+    timer: Timer,
+}
+
+impl Shield {
+    pub fn new(seconds: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DamageKind {
+    Pierce,
+    Slash,
+    Magic,
+    Siege,
+}
+
+#[derive(Component, Clone, Copy)]
+pub struct Armor {
+    pub pierce: f32,
+    pub slash: f32,
+    pub magic: f32,
+    pub siege: f32,
+}
+
+impl Armor {
+    pub const NONE: Armor = Armor {
+        pierce: 1.0,
+        slash: 1.0,
+        magic: 1.0,
+        siege: 1.0,
+    };
+
+    pub fn multiplier(&self, kind: DamageKind) -> f32 {
+        match kind {
+            DamageKind::Pierce => self.pierce,
+            DamageKind::Slash => self.slash,
+            DamageKind::Magic => self.magic,
+            DamageKind::Siege => self.siege,
+        }
+    }
+}
+
+// bouncing projectiles glance off these instead of damaging them
+#[derive(Component)]
+pub struct Deflecting;
+
+#[derive(Clone, Copy)]
+pub struct DamageSource {
+    pub entity: Option<Entity>,
+    pub kingdom: Kingdom,
+}
+
+impl DamageSource {
+    pub fn new(entity: Option<Entity>, kingdom: Kingdom) -> Self {
+        Self { entity, kingdom }
+    }
+}
+
+pub struct DamageEvent {
+    pub source: DamageSource,
+    pub target: Entity,
+    pub amount: f32,
+    pub kind: DamageKind,
+    pub effect: Option<StatusEffectBlueprint>,
+    pub knockback: f32,
+}
+
+impl DamageEvent {
+    pub fn new(source: DamageSource, target: Entity, amount: f32, kind: DamageKind) -> Self {
+        Self {
+            source,
+            target,
+            amount,
+            kind,
+            effect: None,
+            knockback: 0.0,
+        }
+    }
+
+    pub fn with_effect(mut self, effect: Option<StatusEffectBlueprint>) -> Self {
+        self.effect = effect;
+        self
+    }
+
+    pub fn with_knockback(mut self, knockback: f32) -> Self {
+        self.knockback = knockback;
+        self
+    }
+}
+
+pub struct DeathEvent {
+    pub entity: Entity,
+    pub kingdom: Kingdom,
+    pub killer: DamageSource,
+}
+
+impl DeathEvent {
+    pub fn new(entity: Entity, kingdom: Kingdom, killer: DamageSource) -> Self {
+        Self {
+            entity,
+            kingdom,
+            killer,
+        }
+    }
+}
+
+// a single pass per kingdom, the first entry to reach a lane is its frontline
+fn update_frontlines(index: Res<SpatialIndex>, mut frontlines: ResMut<Frontlines>) {
+    *frontlines = Frontlines::default();
+    for kingdom in [Kingdom::Elven, Kingdom::Monster] {
+        let mut unset = 2 * LANE_COUNT;
+        for entry in index.advancing(kingdom) {
+            for (lane, lane_frontlines) in Lane::ALL.iter().zip(&mut frontlines.lanes) {
+                let frontline = lane_frontlines.get_mut(kingdom, entry.flying);
+                if frontline.entity.is_none() && shares_lane(Some(lane), entry.lane.as_ref()) {
+                    *frontline = Frontline {
+                        position: entry.position,
+                        entity: Some(entry.entity),
+                    };
+                    unset -= 1;
                 }
             }
-            Kingdom::Monster => {
-                if transform.translation.x < frontlines.monster.position {
-                    frontlines.monster.position = transform.translation.x;
-                    frontlines.monster.entity = Some(entity);
-                }
+            if unset == 0 {
+                break;
             }
         }
     }
 }
+
+fn regenerate_health(mut query: Query<(&mut Health, &Regeneration)>, time: Res<Time>) {
+    for (mut health, regeneration) in &mut query {
+        if !health.is_dead() {
+            health.heal(**regeneration * time.delta_seconds());
+        }
+    }
+}
+
+fn resolve_damage(
+    mut damage_events: EventReader<DamageEvent>,
+    mut death_events: EventWriter<DeathEvent>,
+    mut query: Query<(
+        &Kingdom,
+        &mut Health,
+        Option<&Armor>,
+        Option<&mut StatusEffects>,
+        Option<&mut Knockback>,
+        Option<&Shield>,
+    )>,
+) {
+    for event in damage_events.iter() {
+        let Ok((kingdom, mut health, armor, status_effects, knockback, shield)) =
+            query.get_mut(event.target)
+        else {
+            continue;
+        };
+        if health.is_dead() || shield.is_some() {
+            continue;
+        }
+
+        // modifiers are applied in order, add new on-hit effects here
+        let mut amount = event.amount;
+        if let Some(armor) = armor {
+            amount *= armor.multiplier(event.kind);
+        }
+
+        health.damage(amount);
+        if health.is_dead() {
+            death_events.send(DeathEvent::new(event.target, *kingdom, event.source));
+            continue;
+        }
+
+        if let (Some(effect), Some(mut status_effects)) = (event.effect, status_effects) {
+            status_effects.apply(&effect, event.source);
+        }
+        if let Some(mut knockback) = knockback.filter(|_| event.knockback > 0.0) {
+            let direction = match event.source.kingdom {
+                Kingdom::Elven => 1.0,
+                Kingdom::Monster => -1.0,
+            };
+            knockback.push(direction * event.knockback);
+        }
+    }
+}
+
+fn tick_shields(mut query: Query<(Entity, &mut Shield)>, time: Res<Time>, mut commands: Commands) {
+    for (entity, mut shield) in &mut query {
+        shield.timer.tick(time.delta());
+        if shield.timer.finished() {
+            commands.entity(entity).remove::<Shield>();
+        }
+    }
+}
+
+fn tick_knockbacks(mut query: Query<&mut Knockback>, time: Res<Time>) {
+    for mut knockback in &mut query {
+        knockback.timer.tick(time.delta());
+    }
+}
@@ -1,51 +1,297 @@
+use crate::{
+    status::{StatusEffectBlueprint, StatusEffectKind},
+    subject::content::{SubjectBlueprint, GOBLIN_MINION},
+    unit::DamageKind,
+};
+
+use super::ProjectileBehaviour;
+
 pub struct WeaponBlueprint {
     pub damage: u32,
+    pub damage_kind: DamageKind,
+    pub effect: Option<StatusEffectBlueprint>,
+    pub knockback: f32,
     pub kind: WeaponKind,
 }
 
 pub enum WeaponKind {
     Sword,
+    Lance(LanceBlueprint),
     Bow(BowBlueprint),
+    Bomb(BombBlueprint),
+    Heal(HealBlueprint),
+    Summon(SummonBlueprint),
+}
+
+pub struct LanceBlueprint {
+    pub charge_distance: f32,
+    pub charge_multiplier: f32,
 }
 
 pub struct BowBlueprint {
     pub range: f32,
     pub speed: f32,
     pub spread: f32,
+    pub accuracy: f32,
+    pub recharge_seconds: f32,
+    pub anti_air: bool,
+    pub projectile: ProjectileBehaviour,
+}
+
+pub struct BombBlueprint {
+    pub range: f32,
+    pub speed: f32,
+    pub spread: f32,
+    pub accuracy: f32,
     pub recharge_seconds: f32,
+    pub explosion: ExplosionBlueprint,
+}
+
+pub struct HealBlueprint {
+    pub range: f32,
+    pub recharge_seconds: f32,
+}
+
+pub struct SummonBlueprint {
+    pub minion: &'static SubjectBlueprint,
+    pub interval_seconds: f32,
+    pub lifetime_seconds: f32,
+}
+
+pub struct ExplosionBlueprint {
+    pub radius: f32,
+    pub falloff: f32,
+    pub fire: Option<FireBlueprint>,
+}
+
+pub struct FireBlueprint {
+    pub damage: u32,
+    pub damage_kind: DamageKind,
+    pub radius: f32,
+    pub interval_seconds: f32,
+    pub lifetime_seconds: f32,
 }
 
 pub const ELVEN_BOW: WeaponBlueprint = WeaponBlueprint {
     damage: 1,
+    damage_kind: DamageKind::Pierce,
+    effect: None,
+    knockback: 0.0,
     kind: WeaponKind::Bow(BowBlueprint {
         range: 15.0,
         speed: 15.0,
         spread: 1.5,
+        accuracy: 0.5,
         recharge_seconds: 2.3,
+        anti_air: true,
+        projectile: ProjectileBehaviour::Sticking,
     }),
 };
 
 pub const ELVEN_FAST_BOW: WeaponBlueprint = WeaponBlueprint {
     damage: 1,
+    damage_kind: DamageKind::Pierce,
+    effect: None,
+    knockback: 0.0,
     kind: WeaponKind::Bow(BowBlueprint {
         range: 10.0,
         speed: 25.0,
         spread: 2.0,
+        accuracy: 0.0,
         recharge_seconds: 0.3,
+        anti_air: true,
+        projectile: ProjectileBehaviour::Bouncing,
     }),
 };
 
 pub const ELVEN_SNIPER_BOW: WeaponBlueprint = WeaponBlueprint {
     damage: 2,
+    damage_kind: DamageKind::Pierce,
+    effect: None,
+    knockback: 6.0,
     kind: WeaponKind::Bow(BowBlueprint {
         range: 100.0,
         speed: 15.0,
         spread: 0.0,
+        accuracy: 1.0,
         recharge_seconds: 0.0,
+        anti_air: false,
+        projectile: ProjectileBehaviour::Piercing {
+            hits: 3,
+            falloff: 0.5,
+        },
+    }),
+};
+
+pub const ELVEN_FROST_BOW: WeaponBlueprint = WeaponBlueprint {
+    damage: 1,
+    damage_kind: DamageKind::Magic,
+    effect: Some(StatusEffectBlueprint {
+        kind: StatusEffectKind::Slow(0.5),
+        seconds: 3.0,
+    }),
+    knockback: 0.0,
+    kind: WeaponKind::Bow(BowBlueprint {
+        range: 14.0,
+        speed: 15.0,
+        spread: 1.0,
+        accuracy: 0.5,
+        recharge_seconds: 3.0,
+        anti_air: true,
+        projectile: ProjectileBehaviour::Sticking,
+    }),
+};
+
+pub const ELVEN_HERO_BOW: WeaponBlueprint = WeaponBlueprint {
+    damage: 3,
+    damage_kind: DamageKind::Pierce,
+    effect: None,
+    knockback: 1.0,
+    kind: WeaponKind::Bow(BowBlueprint {
+        range: 18.0,
+        speed: 18.0,
+        spread: 0.5,
+        accuracy: 1.0,
+        recharge_seconds: 1.5,
+        anti_air: true,
+        projectile: ProjectileBehaviour::Piercing {
+            hits: 2,
+            falloff: 0.5,
+        },
+    }),
+};
+
+pub const ELVEN_STAFF: WeaponBlueprint = WeaponBlueprint {
+    damage: 1,
+    damage_kind: DamageKind::Magic,
+    effect: None,
+    knockback: 0.0,
+    kind: WeaponKind::Heal(HealBlueprint {
+        range: 6.0,
+        recharge_seconds: 2.0,
+    }),
+};
+
+pub const GOBLIN_TOTEM: WeaponBlueprint = WeaponBlueprint {
+    damage: 0,
+    damage_kind: DamageKind::Magic,
+    effect: None,
+    knockback: 0.0,
+    kind: WeaponKind::Summon(SummonBlueprint {
+        minion: &GOBLIN_MINION,
+        interval_seconds: 6.0,
+        lifetime_seconds: 15.0,
     }),
 };
 
 pub const GOBLIN_SWORD: WeaponBlueprint = WeaponBlueprint {
     damage: 1,
+    damage_kind: DamageKind::Slash,
+    effect: None,
+    knockback: 0.0,
+    kind: WeaponKind::Sword,
+};
+
+pub const GOBLIN_DAGGER: WeaponBlueprint = WeaponBlueprint {
+    damage: 1,
+    damage_kind: DamageKind::Slash,
+    effect: Some(StatusEffectBlueprint {
+        kind: StatusEffectKind::Poison(0.5),
+        seconds: 4.0,
+    }),
+    knockback: 0.0,
+    kind: WeaponKind::Sword,
+};
+
+pub const GOBLIN_SPEAR: WeaponBlueprint = WeaponBlueprint {
+    damage: 1,
+    damage_kind: DamageKind::Pierce,
+    effect: None,
+    knockback: 2.0,
+    kind: WeaponKind::Lance(LanceBlueprint {
+        charge_distance: 5.0,
+        charge_multiplier: 4.0,
+    }),
+};
+
+pub const GOBLIN_CLUB: WeaponBlueprint = WeaponBlueprint {
+    damage: 2,
+    damage_kind: DamageKind::Slash,
+    effect: None,
+    knockback: 8.0,
     kind: WeaponKind::Sword,
 };
+
+pub const GOBLIN_WARLORD_AXE: WeaponBlueprint = WeaponBlueprint {
+    damage: 3,
+    damage_kind: DamageKind::Slash,
+    effect: None,
+    knockback: 4.0,
+    kind: WeaponKind::Sword,
+};
+
+pub const GOBLIN_BOMB: WeaponBlueprint = WeaponBlueprint {
+    damage: 2,
+    damage_kind: DamageKind::Siege,
+    effect: Some(StatusEffectBlueprint {
+        kind: StatusEffectKind::Burn(1.0),
+        seconds: 3.0,
+    }),
+    knockback: 0.0,
+    kind: WeaponKind::Bomb(BombBlueprint {
+        range: 8.0,
+        speed: 8.0,
+        spread: 1.0,
+        accuracy: 0.0,
+        recharge_seconds: 4.0,
+        explosion: ExplosionBlueprint {
+            radius: 1.5,
+            falloff: 0.5,
+            fire: Some(FireBlueprint {
+                damage: 1,
+                damage_kind: DamageKind::Magic,
+                radius: 1.0,
+                interval_seconds: 1.0,
+                lifetime_seconds: 4.0,
+            }),
+        },
+    }),
+};
+
+pub const GOBLIN_DROPPED_BOMB: WeaponBlueprint = WeaponBlueprint {
+    damage: 1,
+    damage_kind: DamageKind::Siege,
+    effect: None,
+    knockback: 0.0,
+    kind: WeaponKind::Bomb(BombBlueprint {
+        range: 3.0,
+        speed: 2.0,
+        spread: 0.5,
+        accuracy: 0.0,
+        recharge_seconds: 3.0,
+        explosion: ExplosionBlueprint {
+            radius: 1.2,
+            falloff: 0.5,
+            fire: None,
+        },
+    }),
+};
+
+pub const TOWER_BOW: WeaponBlueprint = WeaponBlueprint {
+    damage: 1,
+    damage_kind: DamageKind::Pierce,
+    effect: None,
+    knockback: 0.0,
+    kind: WeaponKind::Bow(BowBlueprint {
+        range: 18.0,
+        speed: 18.0,
+        spread: 1.0,
+        accuracy: 0.5,
+        recharge_seconds: 1.5,
+        anti_air: true,
+        projectile: ProjectileBehaviour::Piercing {
+            hits: 2,
+            falloff: 0.5,
+        },
+    }),
+};
@@ -0,0 +1,256 @@
+use bevy::prelude::*;
+use bevy_kira_audio::prelude::*;
+
+use crate::{
+    lane::{shares_lane, Lane},
+    palette,
+    spatial::SpatialIndex,
+    status::StatusEffectBlueprint,
+    subject::{DamageSubjects, Flying},
+    unit::{DamageEvent, DamageKind, DamageSource, Health},
+    weapon::{content::ExplosionBlueprint, Lifetime},
+    AppState,
+};
+
+const BURST_SECONDS: f32 = 0.3;
+const FIRE_HEIGHT: f32 = 0.25;
+
+#[derive(SystemLabel)]
+pub struct Explode;
+
+pub struct ExplosionPlugin;
+
+impl Plugin for ExplosionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ExplosionAssets>()
+            .add_event::<ExplosionEvent>()
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn_explosions))
+            .add_system(explode.label(Explode).label(DamageSubjects))
+            .add_system(fade_bursts)
+            .add_system(burn_fires.label(DamageSubjects));
+    }
+}
+
+#[derive(Resource)]
+struct ExplosionAssets {
+    explosion_sound: Handle<AudioSource>,
+}
+
+impl FromWorld for ExplosionAssets {
+    fn from_world(world: &mut World) -> Self {
+        let asset_server: &AssetServer = world.resource();
+        ExplosionAssets {
+            explosion_sound: asset_server.load("sounds/wipe_out.wav"),
+        }
+    }
+}
+
+pub struct ExplosionEvent {
+    position: Vec3,
+    source: DamageSource,
+    damage: u32,
+    damage_kind: DamageKind,
+    effect: Option<StatusEffectBlueprint>,
+    lane: Lane,
+    blueprint: &'static ExplosionBlueprint,
+}
+
+impl ExplosionEvent {
+    pub fn new(
+        position: Vec3,
+        source: DamageSource,
+        damage: u32,
+        damage_kind: DamageKind,
+        effect: Option<StatusEffectBlueprint>,
+        lane: Lane,
+        blueprint: &'static ExplosionBlueprint,
+    ) -> Self {
+        Self {
+            position,
+            source,
+            damage,
+            damage_kind,
+            effect,
+            lane,
+            blueprint,
+        }
+    }
+}
+
+#[derive(Component)]
+struct Burst {
+    timer: Timer,
+}
+
+impl Burst {
+    fn new() -> Self {
+        Self {
+            timer: Timer::from_seconds(BURST_SECONDS, TimerMode::Once),
+        }
+    }
+}
+
+#[derive(Component)]
+struct Fire {
+    source: DamageSource,
+    damage: u32,
+    damage_kind: DamageKind,
+    radius: f32,
+    lane: Lane,
+    timer: Timer,
+}
+
+impl Fire {
+    fn new(
+        source: DamageSource,
+        damage: u32,
+        damage_kind: DamageKind,
+        radius: f32,
+        lane: Lane,
+        interval_seconds: f32,
+    ) -> Self {
+        Self {
+            source,
+            damage,
+            damage_kind,
+            radius,
+            lane,
+            timer: Timer::from_seconds(interval_seconds, TimerMode::Repeating),
+        }
+    }
+}
+
+fn explode(
+    mut explosion_events: EventReader<ExplosionEvent>,
+    health_query: Query<(&Transform, &Health, Option<&Lane>)>,
+    index: Res<SpatialIndex>,
+    mut damage_events: EventWriter<DamageEvent>,
+    assets: Res<ExplosionAssets>,
+    audio: Res<Audio>,
+    mut commands: Commands,
+) {
+    for event in explosion_events.iter() {
+        let radius = event.blueprint.radius;
+        let rival = event.source.kingdom.rival();
+        for entry in index.within(rival, event.position.x, radius) {
+            let Ok((transform, health, lane)) = health_query.get(entry.entity) else {
+                continue;
+            };
+            // laneless targets reach through every lane, so only their x distance counts
+            let distance = match lane {
+                Some(_) => transform
+                    .translation
+                    .truncate()
+                    .distance(event.position.truncate()),
+                None => (transform.translation.x - event.position.x).abs(),
+            };
+            if health.is_dead() || !shares_lane(Some(&event.lane), lane) || distance > radius {
+                continue;
+            }
+
+            let falloff = 1.0 - event.blueprint.falloff * distance / radius;
+            damage_events.send(
+                DamageEvent::new(
+                    event.source,
+                    entry.entity,
+                    event.damage as f32 * falloff,
+                    event.damage_kind,
+                )
+                .with_effect(event.effect),
+            );
+        }
+
+        commands.spawn((
+            Name::new("Burst"),
+            SpriteBundle {
+                sprite: Sprite {
+                    color: palette::DARK_ORANGE,
+                    custom_size: Some(Vec2::splat(radius * 2.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation(event.position + Vec3::Z),
+                ..default()
+            },
+            Burst::new(),
+        ));
+
+        if let Some(fire) = &event.blueprint.fire {
+            commands.spawn((
+                Name::new("Fire"),
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: palette::LIGHT_RED,
+                        custom_size: Some(Vec2::new(fire.radius * 2.0, FIRE_HEIGHT)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(
+                        event.position.x,
+                        event.lane.ground(),
+                        event.lane.depth() + 0.5,
+                    ),
+                    ..default()
+                },
+                Lifetime::new(fire.lifetime_seconds),
+                Fire::new(
+                    event.source,
+                    fire.damage,
+                    fire.damage_kind,
+                    fire.radius,
+                    event.lane,
+                    fire.interval_seconds,
+                ),
+            ));
+        }
+
+        let sound = assets.explosion_sound.clone();
+        audio.play(sound).with_volume(0.3);
+    }
+}
+
+fn fade_bursts(
+    mut query: Query<(Entity, &mut Sprite, &mut Burst)>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    for (entity, mut sprite, mut burst) in &mut query {
+        burst.timer.tick(time.delta());
+        sprite.color.set_a(burst.timer.percent_left());
+        if burst.timer.finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn burn_fires(
+    mut fire_query: Query<(&Transform, &mut Fire)>,
+    health_query: Query<(&Health, Option<&Lane>), Without<Flying>>,
+    index: Res<SpatialIndex>,
+    mut events: EventWriter<DamageEvent>,
+    time: Res<Time>,
+) {
+    for (fire_transform, mut fire) in &mut fire_query {
+        fire.timer.tick(time.delta());
+        let rival = fire.source.kingdom.rival();
+        for _ in 0..fire.timer.times_finished_this_tick() {
+            for entry in index.within(rival, fire_transform.translation.x, fire.radius) {
+                let Ok((health, lane)) = health_query.get(entry.entity) else {
+                    continue;
+                };
+                if !health.is_dead() && shares_lane(Some(&fire.lane), lane) {
+                    events.send(DamageEvent::new(
+                        fire.source,
+                        entry.entity,
+                        fire.damage as f32,
+                        fire.damage_kind,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+fn despawn_explosions(query: Query<Entity, Or<(With<Burst>, With<Fire>)>>, mut commands: Commands) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
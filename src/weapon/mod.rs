@@ -1,42 +1,81 @@
 pub mod content;
+pub mod explosion;
 
-use bevy::{prelude::*, sprite::Anchor};
+use std::time::Duration;
+
+use bevy::{ecs::system::EntityCommands, prelude::*, sprite::Anchor};
 use bevy_kira_audio::prelude::*;
-use bevy_rapier2d::prelude::*;
 
 use crate::{
-    collision::{intersections_with, ColliderBundle},
-    subject::{state::UpdateSubjectState, DamageSubjects, Subject},
-    unit::{Frontlines, Health},
+    collision::{
+        intersections_with, Collider, ColliderBundle, CollisionContext, KingdomGroups, RigidBody,
+        Velocity,
+    },
+    lane::{shares_lane, Lane},
+    palette,
+    spatial::SpatialIndex,
+    status::{StatusEffectBlueprint, Stunned},
+    subject::{
+        content::SubjectBlueprint,
+        state::{damaged_friendly_in_range, ChargingState, RoutingState, UpdateSubjectState},
+        DamageSubjects, DespawnSubjects, Flying, SpawnEvent, SpawnSubjects,
+    },
+    unit::{DamageEvent, DamageKind, DamageSource, Deflecting, Frontlines, Health, ResolveDamage},
+    wind::Wind,
     AppState, Kingdom, GRAVITY_ACCELERATION, PX_PER_METER,
 };
 
+use self::{
+    content::{ExplosionBlueprint, WeaponBlueprint, WeaponKind},
+    explosion::{Explode, ExplosionEvent, ExplosionPlugin},
+};
+
 pub const MAX_ARROW_DEPTH: f32 = 0.125;
+const BOUNCE_RESTITUTION: f32 = 0.3;
+
+#[derive(SystemLabel)]
+pub struct ShootBows;
 
 pub struct WeaponPlugin;
 
 impl Plugin for WeaponPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<WeaponAssets>()
+        app.add_plugin(ExplosionPlugin)
+            .init_resource::<WeaponAssets>()
             .add_event::<ShotEvent>()
+            .add_event::<HealEvent>()
             .add_system_set(SystemSet::on_exit(AppState::Game).with_system(despawn_arrows))
             .add_system(swing_swords.label(DamageSubjects))
             .add_system(tick_bows)
-            .add_system(shoot_bows.after(tick_bows).after(UpdateSubjectState))
+            .add_system(
+                shoot_bows
+                    .label(ShootBows)
+                    .after(tick_bows)
+                    .after(UpdateSubjectState),
+            )
+            .add_system(summon_minions.before(SpawnSubjects))
+            .add_system(tick_staffs)
+            .add_system(heal_friendlies.after(tick_staffs).after(UpdateSubjectState))
             .add_system(accelerate_arrows.after(shoot_bows))
             .add_system(rotate_arrows.after(accelerate_arrows))
             .add_system(
                 collide_arrows
                     .label(DamageSubjects)
                     .after(accelerate_arrows)
-                    .after(swing_swords),
+                    .after(swing_swords)
+                    .before(Explode),
+            )
+            .add_system(
+                drop_stuck_arrows
+                    .after(ResolveDamage)
+                    .before(DespawnSubjects),
             )
             .add_system(despawn_lifetimes.after(shoot_bows));
     }
 }
 
 #[derive(Resource)]
-struct WeaponAssets {
+pub struct WeaponAssets {
     arrow_sprite: Handle<Image>,
     arrow_ground_hit_sound: Handle<AudioSource>,
     bow_shot_sound: Handle<AudioSource>,
@@ -55,89 +94,395 @@ impl FromWorld for WeaponAssets {
 
 pub struct ShotEvent {
     bow_entity: Entity,
+    target: Option<Entity>,
 }
 
 impl ShotEvent {
     pub fn new(bow_entity: Entity) -> Self {
-        Self { bow_entity }
+        Self {
+            bow_entity,
+            target: None,
+        }
+    }
+
+    // shoots at the given entity instead of the rival frontline
+    pub fn with_target(mut self, target: Entity) -> Self {
+        self.target = Some(target);
+        self
+    }
+}
+
+pub struct HealEvent {
+    staff_entity: Entity,
+}
+
+impl HealEvent {
+    pub fn new(staff_entity: Entity) -> Self {
+        Self { staff_entity }
     }
 }
 
 #[derive(Component)]
 pub struct Sword {
     damage: u32,
+    damage_kind: DamageKind,
+    effect: Option<StatusEffectBlueprint>,
+    knockback: f32,
 }
 
 impl Sword {
-    pub fn new(damage: u32) -> Self {
-        Self { damage }
+    pub fn new(
+        damage: u32,
+        damage_kind: DamageKind,
+        effect: Option<StatusEffectBlueprint>,
+        knockback: f32,
+    ) -> Self {
+        Self {
+            damage,
+            damage_kind,
+            effect,
+            knockback,
+        }
+    }
+
+    pub fn apply_modifiers(
+        &mut self,
+        blueprint: &'static WeaponBlueprint,
+        modifiers: &WeaponModifiers,
+    ) {
+        self.damage = modifiers.damage(blueprint);
+    }
+}
+
+#[derive(Component)]
+pub struct Charge {
+    min_distance: f32,
+    multiplier: f32,
+    distance: f32,
+    last_position: Option<f32>,
+    pub is_spent: bool,
+}
+
+impl Charge {
+    pub fn new(min_distance: f32, multiplier: f32) -> Self {
+        Self {
+            min_distance,
+            multiplier,
+            distance: 0.0,
+            last_position: None,
+            is_spent: false,
+        }
+    }
+
+    pub fn track(&mut self, position: f32) {
+        if let Some(last_position) = self.last_position {
+            self.distance += (position - last_position).abs();
+        }
+        self.last_position = Some(position);
+    }
+
+    pub fn reset(&mut self) {
+        self.distance = 0.0;
+        self.last_position = None;
+        self.is_spent = false;
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.distance >= self.min_distance
     }
 }
 
 #[derive(Component)]
 pub struct Bow {
     pub damage: u32,
+    pub damage_kind: DamageKind,
     pub range: f32,
     pub spread: f32,
+    pub accuracy: f32,
     pub speed: f32,
     pub timer: Timer,
+    pub projectile: ProjectileBehaviour,
+    pub anti_air: bool,
+    pub effect: Option<StatusEffectBlueprint>,
+    pub knockback: f32,
 }
 
 impl Bow {
-    pub fn new(damage: u32, range: f32, spread: f32, speed: f32, recharge_seconds: f32) -> Self {
+    pub fn new(
+        damage: u32,
+        damage_kind: DamageKind,
+        range: f32,
+        spread: f32,
+        accuracy: f32,
+        speed: f32,
+        recharge_seconds: f32,
+        projectile: ProjectileBehaviour,
+        anti_air: bool,
+        effect: Option<StatusEffectBlueprint>,
+        knockback: f32,
+    ) -> Self {
         let mut timer = Timer::from_seconds(recharge_seconds, TimerMode::Once);
         timer.set_elapsed(timer.duration());
         Self {
             damage,
+            damage_kind,
             range,
             spread,
+            accuracy,
             speed,
             timer,
+            projectile,
+            anti_air,
+            effect,
+            knockback,
+        }
+    }
+
+    pub fn apply_modifiers(
+        &mut self,
+        blueprint: &'static WeaponBlueprint,
+        modifiers: &WeaponModifiers,
+    ) {
+        self.damage = modifiers.damage(blueprint);
+        let recharge_seconds = match &blueprint.kind {
+            WeaponKind::Sword
+            | WeaponKind::Lance(_)
+            | WeaponKind::Heal(_)
+            | WeaponKind::Summon(_) => return,
+            WeaponKind::Bow(k) => k.recharge_seconds,
+            WeaponKind::Bomb(k) => k.recharge_seconds,
+        };
+        let duration = Duration::from_secs_f32(recharge_seconds * modifiers.recharge_multiplier);
+        self.timer.set_duration(duration);
+    }
+}
+
+#[derive(Component)]
+pub struct Staff {
+    pub amount: u32,
+    pub range: f32,
+    pub timer: Timer,
+}
+
+impl Staff {
+    pub fn new(amount: u32, range: f32, recharge_seconds: f32) -> Self {
+        let mut timer = Timer::from_seconds(recharge_seconds, TimerMode::Once);
+        timer.set_elapsed(timer.duration());
+        Self {
+            amount,
+            range,
+            timer,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct Totem {
+    minion: &'static SubjectBlueprint,
+    lifetime_seconds: f32,
+    timer: Timer,
+}
+
+impl Totem {
+    pub fn new(
+        minion: &'static SubjectBlueprint,
+        interval_seconds: f32,
+        lifetime_seconds: f32,
+    ) -> Self {
+        Self {
+            minion,
+            lifetime_seconds,
+            timer: Timer::from_seconds(interval_seconds, TimerMode::Repeating),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct WeaponModifiers {
+    pub damage_bonus: u32,
+    // only applies to bows, not to melee weapons or bombs
+    pub arrow_damage_bonus: u32,
+    pub recharge_multiplier: f32,
+}
+
+impl Default for WeaponModifiers {
+    fn default() -> Self {
+        Self {
+            damage_bonus: 0,
+            arrow_damage_bonus: 0,
+            recharge_multiplier: 1.0,
+        }
+    }
+}
+
+impl WeaponModifiers {
+    fn damage(&self, blueprint: &WeaponBlueprint) -> u32 {
+        let damage = blueprint.damage + self.damage_bonus;
+        match blueprint.kind {
+            WeaponKind::Bow(_) => damage + self.arrow_damage_bonus,
+            _ => damage,
         }
     }
 }
 
+#[derive(Component, Clone, Copy)]
+pub enum ProjectileBehaviour {
+    Piercing { hits: usize, falloff: f32 },
+    Sticking,
+    Bouncing,
+    Exploding(&'static ExplosionBlueprint),
+}
+
 #[derive(Component)]
 struct Arrow {
+    source: DamageSource,
     damage: u32,
+    damage_kind: DamageKind,
+    effect: Option<StatusEffectBlueprint>,
+    knockback: f32,
+    lane: Lane,
     is_grounded: bool,
+    hit_entities: Vec<Entity>,
 }
 
 impl Arrow {
-    pub fn new(damage: u32) -> Self {
+    pub fn new(
+        source: DamageSource,
+        damage: u32,
+        damage_kind: DamageKind,
+        effect: Option<StatusEffectBlueprint>,
+        knockback: f32,
+        lane: Lane,
+    ) -> Self {
         Self {
+            source,
             damage,
+            damage_kind,
+            effect,
+            knockback,
+            lane,
             is_grounded: false,
+            hit_entities: Vec::new(),
         }
     }
 }
 
+#[derive(Component)]
+struct StuckArrow;
+
 #[derive(Component)]
 pub struct Lifetime {
     timer: Timer,
 }
 
 impl Lifetime {
-    fn new(lifetime_seconds: f32) -> Self {
+    pub fn new(lifetime_seconds: f32) -> Self {
         Self {
             timer: Timer::from_seconds(lifetime_seconds, TimerMode::Once),
         }
     }
 }
 
+pub fn insert_weapon(
+    commands: &mut EntityCommands,
+    blueprint: &'static WeaponBlueprint,
+    modifiers: &WeaponModifiers,
+) {
+    let damage = modifiers.damage(blueprint);
+    match &blueprint.kind {
+        WeaponKind::Sword => commands.insert(Sword::new(
+            damage,
+            blueprint.damage_kind,
+            blueprint.effect,
+            blueprint.knockback,
+        )),
+        WeaponKind::Lance(k) => commands.insert((
+            Sword::new(
+                damage,
+                blueprint.damage_kind,
+                blueprint.effect,
+                blueprint.knockback,
+            ),
+            Charge::new(k.charge_distance, k.charge_multiplier),
+        )),
+        WeaponKind::Bow(k) => commands.insert(Bow::new(
+            damage,
+            blueprint.damage_kind,
+            k.range,
+            k.spread,
+            k.accuracy,
+            k.speed,
+            k.recharge_seconds * modifiers.recharge_multiplier,
+            k.projectile,
+            k.anti_air,
+            blueprint.effect,
+            blueprint.knockback,
+        )),
+        WeaponKind::Bomb(k) => commands.insert(Bow::new(
+            damage,
+            blueprint.damage_kind,
+            k.range,
+            k.spread,
+            k.accuracy,
+            k.speed,
+            k.recharge_seconds * modifiers.recharge_multiplier,
+            ProjectileBehaviour::Exploding(&k.explosion),
+            false,
+            blueprint.effect,
+            blueprint.knockback,
+        )),
+        WeaponKind::Heal(k) => {
+            commands.insert(Staff::new(blueprint.damage, k.range, k.recharge_seconds))
+        }
+        WeaponKind::Summon(k) => {
+            commands.insert(Totem::new(k.minion, k.interval_seconds, k.lifetime_seconds))
+        }
+    };
+}
+
 fn swing_swords(
-    sword_query: Query<(Entity, &Kingdom, &Sword)>,
-    mut health_query: Query<(&Kingdom, &mut Health)>,
-    context: Res<RapierContext>,
+    mut sword_query: Query<
+        (
+            Entity,
+            &Kingdom,
+            &Sword,
+            Option<&mut Charge>,
+            Option<&ChargingState>,
+            Option<&Lane>,
+        ),
+        (Without<Stunned>, Without<RoutingState>),
+    >,
+    health_query: Query<(&Kingdom, Option<&Lane>), (With<Health>, Without<Flying>)>,
+    context: Res<CollisionContext>,
+    mut events: EventWriter<DamageEvent>,
 ) {
-    for (sword_entity, sword_kingdom, sword) in &sword_query {
+    for (sword_entity, sword_kingdom, sword, mut charge, charging_state, sword_lane) in
+        &mut sword_query
+    {
+        let charge_multiplier = match (&charge, charging_state) {
+            (Some(charge), Some(_)) if !charge.is_spent => charge.multiplier,
+            _ => 1.0,
+        };
+
         for health_entity in intersections_with(sword_entity, &context) {
-            let Ok((health_kingdom, mut health)) = health_query.get_mut(health_entity) else {
+            let Ok((health_kingdom, health_lane)) = health_query.get(health_entity) else {
                 continue;
             };
 
-            if health_kingdom != sword_kingdom {
-                health.damage(sword.damage);
+            if health_kingdom != sword_kingdom && shares_lane(sword_lane, health_lane) {
+                if let (Some(charge), Some(_)) = (&mut charge, charging_state) {
+                    charge.is_spent = true;
+                }
+                events.send(
+                    DamageEvent::new(
+                        DamageSource::new(Some(sword_entity), *sword_kingdom),
+                        health_entity,
+                        sword.damage as f32 * charge_multiplier,
+                        sword.damage_kind,
+                    )
+                    .with_effect(sword.effect)
+                    .with_knockback(sword.knockback),
+                );
             }
         }
     }
@@ -149,27 +494,85 @@ fn tick_bows(mut query: Query<&mut Bow>, time: Res<Time>) {
     }
 }
 
+fn summon_minions(
+    mut query: Query<(&Transform, &Kingdom, &Lane, &mut Totem)>,
+    mut events: EventWriter<SpawnEvent>,
+    time: Res<Time>,
+) {
+    for (transform, kingdom, lane, mut totem) in &mut query {
+        totem.timer.tick(time.delta());
+        for _ in 0..totem.timer.times_finished_this_tick() {
+            let mut position = transform.translation;
+            position.y = lane.ground();
+            events.send(SpawnEvent::summoned(
+                totem.minion,
+                position,
+                *lane,
+                *kingdom,
+                totem.lifetime_seconds,
+            ));
+        }
+    }
+}
+
+fn tick_staffs(mut query: Query<&mut Staff>, time: Res<Time>) {
+    for mut staff in &mut query {
+        staff.timer.tick(time.delta());
+    }
+}
+
+fn heal_friendlies(
+    mut events: EventReader<HealEvent>,
+    mut staff_query: Query<(&Transform, &Kingdom, &mut Staff, Option<&Lane>)>,
+    mut health_query: Query<&mut Health>,
+    index: Res<SpatialIndex>,
+) {
+    for event in events.iter() {
+        let Ok((transform, kingdom, mut staff, lane)) = staff_query.get_mut(event.staff_entity)
+        else {
+            continue;
+        };
+        staff.timer.reset();
+
+        let target = damaged_friendly_in_range(
+            transform,
+            kingdom,
+            lane,
+            &staff,
+            &index,
+            &health_query.to_readonly(),
+        );
+        if let Some(target) = target {
+            if let Ok(mut health) = health_query.get_mut(target) {
+                health.heal(staff.amount as f32);
+            }
+        }
+    }
+}
+
 fn shoot_bows(
     mut events: EventReader<ShotEvent>,
-    mut bow_query: Query<(&Transform, &Kingdom, &mut Bow)>,
-    target_query: Query<(&Transform, &Velocity), With<Subject>>,
+    mut bow_query: Query<(&Transform, &Kingdom, &mut Bow, Option<&Lane>)>,
+    target_query: Query<(&Transform, Option<&Velocity>, Option<&Lane>), With<Health>>,
     frontlines: Res<Frontlines>,
+    wind: Res<Wind>,
     assets: Res<WeaponAssets>,
     audio: Res<Audio>,
     mut commands: Commands,
 ) {
     for event in events.iter() {
-        let Ok((bow_transform, kingdom, mut bow)) = bow_query.get_mut(event.bow_entity) else {
+        let Ok((bow_transform, kingdom, mut bow, bow_lane)) = bow_query.get_mut(event.bow_entity)
+        else {
             continue;
         };
-        let frontline_entity = match kingdom {
-            Kingdom::Elven => frontlines.monster.entity,
-            Kingdom::Monster => frontlines.elven.entity,
-        };
-        let Some(target_entity) = frontline_entity else {
+        let target_entity = event
+            .target
+            .or_else(|| frontlines.target(*kingdom, bow_lane, bow.anti_air).entity);
+        let Some(target_entity) = target_entity else {
             continue;
         };
-        let Ok((target_transform, target_velocity)) = target_query.get(target_entity) else {
+        let Ok((target_transform, target_velocity, target_lane)) = target_query.get(target_entity)
+        else {
             continue;
         };
         bow.timer.reset();
@@ -183,19 +586,27 @@ fn shoot_bows(
         let random_offset = 0.85 + 0.3 * fastrand::f32();
         let speed = bow.speed * random_offset;
         let velocity_x = speed * diff.x.signum();
-        let relative_velocity_x = velocity_x - target_velocity.linvel.x;
+        let target_velocity_x = target_velocity.map_or(0.0, |velocity| velocity.linvel.x);
+        let relative_velocity_x = velocity_x - target_velocity_x;
         let random_offset = (1.0 - bow.spread / 4.0) + bow.spread * fastrand::f32();
         // TODO: this doesn't work when the target runs away faster than the arrow
         let flight_time = (diff.x / relative_velocity_x) * random_offset;
         // TODO: this doesn't take vertical velocity into account for prediction
         let velocity_y = diff.y / flight_time + GRAVITY_ACCELERATION * flight_time / 2.0;
+        // accurate bows aim against the wind to cancel out its drift
+        let velocity_x = velocity_x - bow.accuracy * wind.acceleration() * flight_time / 2.0;
         let velocity = Vec2::new(velocity_x, velocity_y);
 
         spawn_arrow(
             position,
             velocity,
-            *kingdom,
+            DamageSource::new(Some(event.bow_entity), *kingdom),
             bow.damage,
+            bow.damage_kind,
+            bow.effect,
+            bow.knockback,
+            bow.projectile,
+            target_lane.or(bow_lane).copied().unwrap_or_default(),
             &assets,
             &mut commands,
         );
@@ -205,15 +616,16 @@ fn shoot_bows(
     }
 }
 
-fn despawn_arrows(query: Query<Entity, With<Arrow>>, mut commands: Commands) {
+fn despawn_arrows(query: Query<Entity, (With<Arrow>, Without<Parent>)>, mut commands: Commands) {
     for entity in &query {
         commands.entity(entity).despawn_recursive();
     }
 }
 
-fn accelerate_arrows(mut query: Query<(&mut Velocity, &Arrow)>, time: Res<Time>) {
+fn accelerate_arrows(mut query: Query<(&mut Velocity, &Arrow)>, wind: Res<Wind>, time: Res<Time>) {
     for (mut velocity, arrow) in &mut query {
         if !arrow.is_grounded {
+            velocity.linvel.x += wind.acceleration() * time.delta_seconds();
             velocity.linvel.y -= GRAVITY_ACCELERATION * time.delta_seconds();
         }
     }
@@ -229,20 +641,58 @@ fn rotate_arrows(mut query: Query<(&mut Transform, &Velocity), With<Arrow>>) {
 }
 
 fn collide_arrows(
-    mut arrow_query: Query<(Entity, &mut Transform, &mut Velocity, &Kingdom, &mut Arrow)>,
-    mut health_query: Query<(&Kingdom, &mut Health)>,
-    context: Res<RapierContext>,
+    mut arrow_query: Query<(
+        Entity,
+        &mut Transform,
+        &mut Velocity,
+        &Kingdom,
+        &ProjectileBehaviour,
+        &mut Arrow,
+    )>,
+    health_query: Query<
+        (
+            &Transform,
+            &Kingdom,
+            &Health,
+            Option<&Deflecting>,
+            Option<&Lane>,
+        ),
+        Without<Arrow>,
+    >,
+    context: Res<CollisionContext>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
     weapon_assets: Res<WeaponAssets>,
     audio: Res<Audio>,
     mut commands: Commands,
 ) {
-    for (arrow_entity, mut transform, mut velocity, arrow_kingdom, mut arrow) in &mut arrow_query {
+    for (arrow_entity, mut transform, mut velocity, arrow_kingdom, behaviour, mut arrow) in
+        &mut arrow_query
+    {
         if arrow.is_grounded {
             continue;
         }
 
-        if transform.translation.y <= -MAX_ARROW_DEPTH {
-            transform.translation.y = -MAX_ARROW_DEPTH;
+        let ground = arrow.lane.ground();
+        if let ProjectileBehaviour::Exploding(explosion) = *behaviour {
+            if transform.translation.y <= ground {
+                let position = Vec3::new(transform.translation.x, ground, transform.translation.z);
+                explosion_events.send(ExplosionEvent::new(
+                    position,
+                    arrow.source,
+                    arrow.damage,
+                    arrow.damage_kind,
+                    arrow.effect,
+                    arrow.lane,
+                    explosion,
+                ));
+                commands.entity(arrow_entity).despawn_recursive();
+                continue;
+            }
+        }
+
+        if transform.translation.y <= ground - MAX_ARROW_DEPTH {
+            transform.translation.y = ground - MAX_ARROW_DEPTH;
             velocity.linvel = Vec2::ZERO;
             arrow.is_grounded = true;
 
@@ -253,15 +703,113 @@ fn collide_arrows(
         }
 
         for health_entity in intersections_with(arrow_entity, &context) {
-            let Ok((health_kingdom, mut health)) = health_query.get_mut(health_entity) else {
+            if arrow.hit_entities.contains(&health_entity) {
+                continue;
+            }
+            let Ok((health_transform, health_kingdom, health, deflecting, health_lane)) =
+                health_query.get(health_entity)
+            else {
                 continue;
             };
 
-            if !health.is_dead() && health_kingdom != arrow_kingdom {
-                health.damage(arrow.damage);
-                commands.entity(arrow_entity).despawn_recursive();
-                break;
+            if health.is_dead()
+                || health_kingdom == arrow_kingdom
+                || !shares_lane(Some(&arrow.lane), health_lane)
+            {
+                continue;
             }
+            arrow.hit_entities.push(health_entity);
+
+            match *behaviour {
+                ProjectileBehaviour::Bouncing if deflecting.is_some() => {
+                    velocity.linvel.x *= -BOUNCE_RESTITUTION;
+                }
+                ProjectileBehaviour::Piercing { hits, falloff } => {
+                    let falloff = falloff.powi(arrow.hit_entities.len() as i32 - 1);
+                    damage_events.send(
+                        DamageEvent::new(
+                            arrow.source,
+                            health_entity,
+                            arrow.damage as f32 * falloff,
+                            arrow.damage_kind,
+                        )
+                        .with_effect(arrow.effect)
+                        .with_knockback(arrow.knockback),
+                    );
+                    if arrow.hit_entities.len() >= hits {
+                        commands.entity(arrow_entity).despawn_recursive();
+                        break;
+                    }
+                }
+                ProjectileBehaviour::Sticking => {
+                    damage_events.send(
+                        DamageEvent::new(
+                            arrow.source,
+                            health_entity,
+                            arrow.damage as f32,
+                            arrow.damage_kind,
+                        )
+                        .with_effect(arrow.effect)
+                        .with_knockback(arrow.knockback),
+                    );
+                    // the target has no rotation or scale, so the offset is enough
+                    transform.translation -= health_transform.translation;
+                    commands
+                        .entity(arrow_entity)
+                        .remove::<(RigidBody, Velocity, ColliderBundle)>()
+                        .insert(StuckArrow);
+                    commands.entity(health_entity).add_child(arrow_entity);
+                    break;
+                }
+                ProjectileBehaviour::Exploding(explosion) => {
+                    explosion_events.send(ExplosionEvent::new(
+                        transform.translation,
+                        arrow.source,
+                        arrow.damage,
+                        arrow.damage_kind,
+                        arrow.effect,
+                        arrow.lane,
+                        explosion,
+                    ));
+                    commands.entity(arrow_entity).despawn_recursive();
+                    break;
+                }
+                _ => {
+                    damage_events.send(
+                        DamageEvent::new(
+                            arrow.source,
+                            health_entity,
+                            arrow.damage as f32,
+                            arrow.damage_kind,
+                        )
+                        .with_effect(arrow.effect)
+                        .with_knockback(arrow.knockback),
+                    );
+                    commands.entity(arrow_entity).despawn_recursive();
+                    break;
+                }
+            }
+        }
+    }
+}
+
+fn drop_stuck_arrows(
+    mut arrow_query: Query<(Entity, &mut Transform, &GlobalTransform, &Parent), With<StuckArrow>>,
+    health_query: Query<&Health>,
+    mut commands: Commands,
+) {
+    for (arrow_entity, mut transform, global_transform, parent) in &mut arrow_query {
+        let Ok(health) = health_query.get(parent.get()) else {
+            continue;
+        };
+
+        if health.is_dead() {
+            *transform = global_transform.compute_transform();
+            commands
+                .entity(arrow_entity)
+                .remove_parent()
+                .remove::<StuckArrow>()
+                .insert((RigidBody::KinematicVelocityBased, Velocity::zero()));
         }
     }
 }
@@ -279,11 +827,16 @@ fn despawn_lifetimes(
     }
 }
 
-fn spawn_arrow(
+pub fn spawn_arrow(
     position: Vec3,
     velocity: Vec2,
-    kingdom: Kingdom,
+    source: DamageSource,
     damage: u32,
+    damage_kind: DamageKind,
+    effect: Option<StatusEffectBlueprint>,
+    knockback: f32,
+    behaviour: ProjectileBehaviour,
+    lane: Lane,
     assets: &WeaponAssets,
     commands: &mut Commands,
 ) {
@@ -291,16 +844,21 @@ fn spawn_arrow(
         Name::new("Arrow"),
         SpatialBundle::from_transform(Transform::from_translation(position)),
         RigidBody::KinematicVelocityBased,
-        ColliderBundle::new(Collider::ball(0.05)),
+        ColliderBundle::new(Collider::ball(0.05), KingdomGroups::rivals(source.kingdom)),
         Velocity::linear(velocity),
         Lifetime::new(20.0),
-        kingdom,
-        Arrow::new(damage),
+        source.kingdom,
+        behaviour,
+        Arrow::new(source, damage, damage_kind, effect, knockback, lane),
     );
     let sprite = SpriteBundle {
         texture: assets.arrow_sprite.clone(),
         // texture_atlas: texture_atlases.add(texture_atlas),
         sprite: Sprite {
+            color: match behaviour {
+                ProjectileBehaviour::Exploding(_) => palette::DARK_BLACK,
+                _ => Color::WHITE,
+            },
             anchor: Anchor::CenterRight,
             ..default()
         },
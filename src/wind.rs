@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+
+const MAX_BASE_ACCELERATION: f32 = 1.5;
+const MAX_GUST_ACCELERATION: f32 = 1.0;
+const GUST_INTERVAL_SECONDS: f32 = 3.0;
+const GUST_SMOOTHING: f32 = 0.5;
+const CALM_PROBABILITY: f32 = 0.25;
+
+pub struct WindPlugin;
+
+impl Plugin for WindPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Wind>()
+            .add_system_set(SystemSet::on_enter(AppState::Game).with_system(randomize_wind))
+            .add_system_set(SystemSet::on_exit(AppState::Game).with_system(reset_wind))
+            .add_system_set(SystemSet::on_update(AppState::Game).with_system(update_gusts));
+    }
+}
+
+#[derive(Resource)]
+pub struct Wind {
+    base: f32,
+    gust: f32,
+    target_gust: f32,
+    timer: Timer,
+}
+
+impl Default for Wind {
+    fn default() -> Self {
+        Self {
+            base: 0.0,
+            gust: 0.0,
+            target_gust: 0.0,
+            timer: Timer::from_seconds(GUST_INTERVAL_SECONDS, TimerMode::Repeating),
+        }
+    }
+}
+
+impl Wind {
+    pub fn acceleration(&self) -> f32 {
+        self.base + self.gust
+    }
+}
+
+fn randomize_wind(mut wind: ResMut<Wind>) {
+    *wind = Wind::default();
+    if fastrand::f32() >= CALM_PROBABILITY {
+        wind.base = MAX_BASE_ACCELERATION * (2.0 * fastrand::f32() - 1.0);
+    }
+}
+
+fn reset_wind(mut wind: ResMut<Wind>) {
+    *wind = Wind::default();
+}
+
+fn update_gusts(mut wind: ResMut<Wind>, time: Res<Time>) {
+    wind.timer.tick(time.delta());
+    if wind.timer.just_finished() {
+        // calm days barely gust, windy ones gust proportionally to their base wind
+        let strength = wind.base.abs() / MAX_BASE_ACCELERATION;
+        wind.target_gust = MAX_GUST_ACCELERATION * strength * (2.0 * fastrand::f32() - 1.0);
+    }
+
+    let step = (time.delta_seconds() / GUST_SMOOTHING).min(1.0);
+    wind.gust += (wind.target_gust - wind.gust) * step;
+}